@@ -1,4 +1,4 @@
 #!/bin/bash
 sleep 5
-sqlx migrate run
-./rust-server
\ No newline at end of file
+./rust-server migrate run
+exec ./rust-server serve --skip-migrations
\ No newline at end of file
@@ -113,6 +113,95 @@ pub mod echo_service_client {
                 .insert(GrpcMethod::new("echo.v1.EchoService", "UnaryEcho"));
             self.inner.unary(req, path, codec).await
         }
+        /** ServerStreamingEcho is server side streaming.
+*/
+        pub async fn server_streaming_echo(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ServerStreamingEchoRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ServerStreamingEchoResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/echo.v1.EchoService/ServerStreamingEcho",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("echo.v1.EchoService", "ServerStreamingEcho"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /** ClientStreamingEcho is client side streaming.
+*/
+        pub async fn client_streaming_echo(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::ClientStreamingEchoRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ClientStreamingEchoResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/echo.v1.EchoService/ClientStreamingEcho",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("echo.v1.EchoService", "ClientStreamingEcho"));
+            self.inner.client_streaming(req, path, codec).await
+        }
+        /** BidirectionalStreamingEcho is bidi streaming.
+*/
+        pub async fn bidirectional_streaming_echo(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::BidirectionalStreamingEchoRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::BidirectionalStreamingEchoResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/echo.v1.EchoService/BidirectionalStreamingEcho",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("echo.v1.EchoService", "BidirectionalStreamingEcho"),
+                );
+            self.inner.streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -131,6 +220,53 @@ pub mod echo_service_server {
             tonic::Response<super::UnaryEchoResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the ServerStreamingEcho method.
+        type ServerStreamingEchoStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ServerStreamingEchoResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /** ServerStreamingEcho is server side streaming.
+*/
+        async fn server_streaming_echo(
+            &self,
+            request: tonic::Request<super::ServerStreamingEchoRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ServerStreamingEchoStream>,
+            tonic::Status,
+        >;
+        /** ClientStreamingEcho is client side streaming.
+*/
+        async fn client_streaming_echo(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::ClientStreamingEchoRequest>>,
+        ) -> std::result::Result<
+            tonic::Response<super::ClientStreamingEchoResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the BidirectionalStreamingEcho method.
+        type BidirectionalStreamingEchoStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::BidirectionalStreamingEchoResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /** BidirectionalStreamingEcho is bidi streaming.
+*/
+        async fn bidirectional_streaming_echo(
+            &self,
+            request: tonic::Request<
+                tonic::Streaming<super::BidirectionalStreamingEchoRequest>,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<Self::BidirectionalStreamingEchoStream>,
+            tonic::Status,
+        >;
     }
     /** Echo is the echo service.
 */
@@ -259,6 +395,159 @@ pub mod echo_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/echo.v1.EchoService/ServerStreamingEcho" => {
+                    #[allow(non_camel_case_types)]
+                    struct ServerStreamingEchoSvc<T: EchoService>(pub Arc<T>);
+                    impl<
+                        T: EchoService,
+                    > tonic::server::ServerStreamingService<
+                        super::ServerStreamingEchoRequest,
+                    > for ServerStreamingEchoSvc<T> {
+                        type Response = super::ServerStreamingEchoResponse;
+                        type ResponseStream = T::ServerStreamingEchoStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ServerStreamingEchoRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EchoService>::server_streaming_echo(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ServerStreamingEchoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/echo.v1.EchoService/ClientStreamingEcho" => {
+                    #[allow(non_camel_case_types)]
+                    struct ClientStreamingEchoSvc<T: EchoService>(pub Arc<T>);
+                    impl<
+                        T: EchoService,
+                    > tonic::server::ClientStreamingService<
+                        super::ClientStreamingEchoRequest,
+                    > for ClientStreamingEchoSvc<T> {
+                        type Response = super::ClientStreamingEchoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::ClientStreamingEchoRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EchoService>::client_streaming_echo(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ClientStreamingEchoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/echo.v1.EchoService/BidirectionalStreamingEcho" => {
+                    #[allow(non_camel_case_types)]
+                    struct BidirectionalStreamingEchoSvc<T: EchoService>(pub Arc<T>);
+                    impl<
+                        T: EchoService,
+                    > tonic::server::StreamingService<
+                        super::BidirectionalStreamingEchoRequest,
+                    > for BidirectionalStreamingEchoSvc<T> {
+                        type Response = super::BidirectionalStreamingEchoResponse;
+                        type ResponseStream = T::BidirectionalStreamingEchoStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::BidirectionalStreamingEchoRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EchoService>::bidirectional_streaming_echo(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BidirectionalStreamingEchoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -1,4 +1,12 @@
 // @generated
+pub mod apikey {
+    #[cfg(feature = "apikey-v1")]
+    // @@protoc_insertion_point(attribute:apikey.v1)
+    pub mod v1 {
+        include!("apikey.v1.rs");
+        // @@protoc_insertion_point(apikey.v1)
+    }
+}
 pub mod echo {
     #[cfg(feature = "echo-v1")]
     // @@protoc_insertion_point(attribute:echo.v1)
@@ -0,0 +1,124 @@
+# Generated by the protocol buffer compiler.  DO NOT EDIT!
+# sources: apikey/v1/apikey.proto
+# plugin: python-betterproto
+from dataclasses import dataclass
+from datetime import datetime
+from typing import List
+
+import betterproto
+import grpclib
+
+
+@dataclass
+class ApiKey(betterproto.Message):
+    """ApiKey describes a key. The secret itself is only returned on creation."""
+
+    id: str = betterproto.string_field(1)
+    # First characters of the secret, to tell keys apart.
+    prefix: str = betterproto.string_field(2)
+    # Service or team the key was issued to.
+    owner: str = betterproto.string_field(3)
+    scopes: List[str] = betterproto.string_field(4)
+    # Unset for keys that never expire.
+    expires_at: datetime = betterproto.message_field(5)
+    last_used_at: datetime = betterproto.message_field(6)
+    created_at: datetime = betterproto.message_field(7)
+    revoked_at: datetime = betterproto.message_field(8)
+
+
+@dataclass
+class CreateApiKeyRequest(betterproto.Message):
+    """CreateApiKeyRequest issues a new key."""
+
+    owner: str = betterproto.string_field(1)
+    scopes: List[str] = betterproto.string_field(2)
+    # Unset for a key that never expires.
+    expires_at: datetime = betterproto.message_field(3)
+
+
+@dataclass
+class CreateApiKeyResponse(betterproto.Message):
+    """CreateApiKeyResponse holds the new key and its secret."""
+
+    api_key: "ApiKey" = betterproto.message_field(1)
+    # Value of the `x-api-key` metadata. Only its hash is stored, it can't be
+    # retrieved later.
+    secret: str = betterproto.string_field(2)
+
+
+@dataclass
+class ListApiKeysRequest(betterproto.Message):
+    """ListApiKeysRequest filters the listed keys."""
+
+    # Lists the keys of every owner when empty.
+    owner: str = betterproto.string_field(1)
+    include_revoked: bool = betterproto.bool_field(2)
+
+
+@dataclass
+class ListApiKeysResponse(betterproto.Message):
+    """ListApiKeysResponse lists keys, newest first."""
+
+    api_keys: List["ApiKey"] = betterproto.message_field(1)
+
+
+@dataclass
+class RevokeApiKeyRequest(betterproto.Message):
+    """RevokeApiKeyRequest revokes a key."""
+
+    id: str = betterproto.string_field(1)
+
+
+@dataclass
+class RevokeApiKeyResponse(betterproto.Message):
+    """RevokeApiKeyResponse returns the revoked key."""
+
+    api_key: "ApiKey" = betterproto.message_field(1)
+
+
+class ApiKeyServiceStub(betterproto.ServiceStub):
+    """ApiKeyService manages the API keys of service-to-service callers."""
+
+    async def create_api_key(
+        self, *, owner: str = "", scopes: List[str] = [], expires_at: datetime = None
+    ) -> CreateApiKeyResponse:
+        """CreateApiKey issues a key."""
+
+        request = CreateApiKeyRequest()
+        request.owner = owner
+        request.scopes.extend(scopes)
+        if expires_at is not None:
+            request.expires_at = expires_at
+
+        return await self._unary_unary(
+            "/apikey.v1.ApiKeyService/CreateApiKey",
+            request,
+            CreateApiKeyResponse,
+        )
+
+    async def list_api_keys(
+        self, *, owner: str = "", include_revoked: bool = False
+    ) -> ListApiKeysResponse:
+        """ListApiKeys lists keys."""
+
+        request = ListApiKeysRequest()
+        request.owner = owner
+        request.include_revoked = include_revoked
+
+        return await self._unary_unary(
+            "/apikey.v1.ApiKeyService/ListApiKeys",
+            request,
+            ListApiKeysResponse,
+        )
+
+    async def revoke_api_key(self, *, id: str = "") -> RevokeApiKeyResponse:
+        """RevokeApiKey revokes a key, it stops working right away."""
+
+        request = RevokeApiKeyRequest()
+        request.id = id
+
+        return await self._unary_unary(
+            "/apikey.v1.ApiKeyService/RevokeApiKey",
+            request,
+            RevokeApiKeyResponse,
+        )
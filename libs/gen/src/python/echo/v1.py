@@ -2,6 +2,7 @@
 # sources: echo/v1/echo.proto
 # plugin: python-betterproto
 from dataclasses import dataclass
+from typing import AsyncGenerator, List
 
 import betterproto
 import grpclib
@@ -21,6 +22,57 @@ class UnaryEchoResponse(betterproto.Message):
     message: str = betterproto.string_field(1)
 
 
+@dataclass
+class ServerStreamingEchoRequest(betterproto.Message):
+    """
+    ServerStreamingEchoRequest asks for the message to be echoed `count`
+    times.
+    """
+
+    message: str = betterproto.string_field(1)
+    # Number of responses, between 1 and 100.
+    count: int = betterproto.uint32_field(2)
+    # Delay between two responses in milliseconds, at most 10000.
+    interval_ms: int = betterproto.uint32_field(3)
+
+
+@dataclass
+class ServerStreamingEchoResponse(betterproto.Message):
+    """ServerStreamingEchoResponse is one of the echoed messages."""
+
+    message: str = betterproto.string_field(1)
+    # Position of the response in the stream, starting at 0.
+    sequence: int = betterproto.uint32_field(2)
+
+
+@dataclass
+class ClientStreamingEchoRequest(betterproto.Message):
+    """ClientStreamingEchoRequest is one of the messages to echo."""
+
+    message: str = betterproto.string_field(1)
+
+
+@dataclass
+class ClientStreamingEchoResponse(betterproto.Message):
+    """ClientStreamingEchoResponse echoes every message of the stream at once."""
+
+    messages: List[str] = betterproto.string_field(1)
+
+
+@dataclass
+class BidirectionalStreamingEchoRequest(betterproto.Message):
+    """BidirectionalStreamingEchoRequest is one of the messages to echo."""
+
+    message: str = betterproto.string_field(1)
+
+
+@dataclass
+class BidirectionalStreamingEchoResponse(betterproto.Message):
+    """BidirectionalStreamingEchoResponse echoes a single message."""
+
+    message: str = betterproto.string_field(1)
+
+
 class EchoServiceStub(betterproto.ServiceStub):
     """Echo is the echo service."""
 
@@ -35,3 +87,20 @@ class EchoServiceStub(betterproto.ServiceStub):
             request,
             UnaryEchoResponse,
         )
+
+    async def server_streaming_echo(
+        self, *, message: str = "", count: int = 0, interval_ms: int = 0
+    ) -> AsyncGenerator[ServerStreamingEchoResponse, None]:
+        """ServerStreamingEcho is server side streaming."""
+
+        request = ServerStreamingEchoRequest()
+        request.message = message
+        request.count = count
+        request.interval_ms = interval_ms
+
+        async for response in self._unary_stream(
+            "/echo.v1.EchoService/ServerStreamingEcho",
+            request,
+            ServerStreamingEchoResponse,
+        ):
+            yield response
@@ -19,10 +19,13 @@ fn impl_tonic_error(ast: &syn::DeriveInput) -> TokenStream {
                 let type_name = to_variant_name(&e).unwrap();
 
                 if e.is_list() {
-                    let error_json = serde_json::json!({
+                    let mut error_json = serde_json::json!({
                         "type": type_name,
                         "errors": e.errors(),
                     });
+                    if let Some(request_id) = e.request_id() {
+                        error_json["request_id"] = serde_json::Value::from(request_id);
+                    }
 
                     let mut status = tonic::Status::new(e.code(), format!("{}", type_name));
 
@@ -36,10 +39,13 @@ fn impl_tonic_error(ast: &syn::DeriveInput) -> TokenStream {
                     return status;
                 }
 
-                let error_json = serde_json::json!({
+                let mut error_json = serde_json::json!({
                     "message": e.to_string(),
                     "type": type_name,
                 });
+                if let Some(request_id) = e.request_id() {
+                    error_json["request_id"] = serde_json::Value::from(request_id);
+                }
 
                 let mut status = tonic::Status::new(e.code(), format!("{}", type_name));
 
@@ -0,0 +1,18 @@
+CREATE TABLE api_keys (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    -- First characters of the key, shown to tell keys apart
+    prefix TEXT NOT NULL,
+    -- SHA-256 of the key, the key itself is never stored
+    key_hash TEXT NOT NULL UNIQUE,
+    owner TEXT NOT NULL,
+    scopes TEXT[] NOT NULL DEFAULT '{}',
+    expires_at TIMESTAMPTZ,
+    last_used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT current_timestamp,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT current_timestamp
+);
+
+CREATE INDEX api_keys_owner_idx ON api_keys (owner);
+
+SELECT manage_updated_at('api_keys');
@@ -0,0 +1,21 @@
+use crate::cli::ApiKeysAction;
+use crate::config::DatabaseOptions;
+use crate::database::{self, get_connection, insert_api_key};
+use crate::utils::deadline::Deadline;
+
+/// Writes to the database directly, so it works before any key exists. Only
+/// the key goes to stdout.
+pub async fn api_keys(options: &DatabaseOptions, action: &ApiKeysAction) -> Result<(), Box<dyn std::error::Error>> {
+    let pool = database::connect(options).await?;
+    let mut conn = get_connection(&pool, Deadline::NONE).await?;
+
+    match action {
+        ApiKeysAction::Create(args) => {
+            let (record, key) = insert_api_key(&mut conn, &args.owner, &args.scopes, args.expires_at).await?;
+            eprintln!("Created API key {} ({}) for {} with scopes {}", record.prefix, record.id, record.owner, record.scopes.join(" "));
+            println!("{}", key);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,167 @@
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use crate::config::{Config, ConfigError};
+
+#[derive(Debug, Parser)]
+#[command(version, about = "gRPC server")]
+pub struct Cli {
+    /// Config file (TOML or YAML), defaults to $CONFIG_FILE
+    #[arg(short, long, global = true, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Overrides a config value, e.g. `--set server.port=50052`
+    #[arg(long = "set", global = true, value_name = "KEY=VALUE")]
+    pub overrides: Vec<String>,
+
+    /// Defaults to `serve`
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run the migrations and start the servers
+    Serve(ServeArgs),
+    /// Manage the database migrations
+    Migrate {
+        #[command(subcommand)]
+        action: MigrateAction,
+    },
+    /// Manage API keys directly in the database, e.g. to create the first
+    /// `api_keys:admin` key
+    ApiKeys {
+        #[command(subcommand)]
+        action: ApiKeysAction,
+    },
+    /// Validate the config and print it with secrets redacted
+    CheckConfig,
+    /// Dump the file descriptor set of every served service
+    PrintDescriptors(PrintDescriptorsArgs),
+    /// Query the health service of a running server, exits non-zero unless it is serving
+    Healthcheck(HealthcheckArgs),
+    /// Write Prometheus alerting rules and a Grafana dashboard for the configured objectives
+    GenerateSlo(GenerateSloArgs),
+}
+
+impl Default for Command {
+    fn default() -> Self {
+        Command::Serve(ServeArgs::default())
+    }
+}
+
+#[derive(Debug, Default, Args)]
+pub struct ServeArgs {
+    /// Don't run the migrations on startup, e.g. when they run as a separate job
+    #[arg(long)]
+    pub skip_migrations: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Subcommand)]
+pub enum MigrateAction {
+    /// Create the database if needed and apply the pending migrations
+    Run,
+    /// List the migrations and whether they are applied
+    Status,
+    /// Revert the last applied migration
+    Revert,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ApiKeysAction {
+    /// Create a key and print it, it can't be recovered afterwards
+    Create(CreateApiKeyArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct CreateApiKeyArgs {
+    /// Who the key belongs to, the `sub` of its claims
+    #[arg(long)]
+    pub owner: String,
+
+    /// Scope granted to the key, repeat for several, e.g. `--scope api_keys:admin`
+    #[arg(long = "scope", value_name = "SCOPE", required = true)]
+    pub scopes: Vec<String>,
+
+    /// RFC 3339 time the key stops working at, never by default
+    #[arg(long)]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DescriptorFormat {
+    /// Encoded `FileDescriptorSet`, e.g. for `grpcurl -protoset`
+    Binary,
+    Json,
+}
+
+#[derive(Debug, Args)]
+pub struct PrintDescriptorsArgs {
+    #[arg(long, value_enum, default_value_t = DescriptorFormat::Binary)]
+    pub format: DescriptorFormat,
+
+    /// Write to a file instead of stdout
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct HealthcheckArgs {
+    /// Server to check, e.g. `http://127.0.0.1:50051` or `unix:///run/grpc.sock`.
+    /// Defaults to the first configured listener.
+    #[arg(long)]
+    pub addr: Option<String>,
+
+    /// Service to check, the whole server by default
+    #[arg(long, default_value = "")]
+    pub service: String,
+
+    /// Seconds to wait for an answer
+    #[arg(long, default_value_t = 3)]
+    pub timeout: u64,
+
+    /// CA bundle to verify the server certificate against, for `https` addresses
+    #[arg(long, value_name = "FILE")]
+    pub ca_cert: Option<PathBuf>,
+
+    /// Name expected in the server certificate, defaults to the address host
+    #[arg(long)]
+    pub domain: Option<String>,
+
+    /// Check `/readyz` on the plaintext admin server instead, e.g. from a
+    /// container health check that has no TLS material. `--addr` is then the
+    /// admin server, by default the metrics port over loopback.
+    #[arg(long, conflicts_with_all = ["service", "ca_cert", "domain"])]
+    pub admin: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct GenerateSloArgs {
+    /// Directory to write `slo-rules.yml` and `slo-dashboard.json` to
+    #[arg(short, long, value_name = "DIR", default_value = ".")]
+    pub output_dir: PathBuf,
+}
+
+impl Cli {
+    /// Loads the config from the global flags, or logs every error and exits
+    /// with status 2.
+    pub fn load_config(&self) -> Config {
+        Cli::config_or_exit(self.try_load_config())
+    }
+
+    pub fn try_load_config(&self) -> Result<Config, ConfigError> {
+        Config::load(self.config.as_deref(), &self.overrides)
+    }
+
+    /// The config, or logs every error and exits with status 2.
+    pub fn config_or_exit(config: Result<Config, ConfigError>) -> Config {
+        match config {
+            Ok(config) => config,
+            Err(e) => {
+                tracing::error!("{}", e);
+                std::process::exit(2);
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::HashSet;
+use std::io::Write;
+
+use pbjson_types::FileDescriptorSet;
+use prost::Message;
+use crate::cli::{DescriptorFormat, PrintDescriptorsArgs};
+use crate::server::AppBuilder;
+
+/// Merges the descriptor sets of every service `app` serves. Files shared
+/// between sets, such as well-known types, are only kept once.
+pub fn file_descriptor_set(app: &AppBuilder) -> Result<FileDescriptorSet, prost::DecodeError> {
+    let mut merged = FileDescriptorSet::default();
+    let mut names = HashSet::new();
+
+    for descriptor_set in app.descriptor_sets() {
+        for file in FileDescriptorSet::decode(descriptor_set)?.file {
+            if names.insert(file.name.clone()) {
+                merged.file.push(file);
+            }
+        }
+    }
+
+    Ok(merged)
+}
+
+pub fn print_descriptors(app: &AppBuilder, args: &PrintDescriptorsArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let descriptor_set = file_descriptor_set(app)?;
+    let bytes = match args.format {
+        DescriptorFormat::Binary => descriptor_set.encode_to_vec(),
+        DescriptorFormat::Json => {
+            let mut json = serde_json::to_vec_pretty(&descriptor_set)?;
+            json.push(b'\n');
+            json
+        }
+    };
+
+    match &args.output {
+        Some(path) => std::fs::write(path, bytes)?,
+        None => std::io::stdout().lock().write_all(&bytes)?,
+    }
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use tokio::net::UnixStream;
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint, Uri};
+use tonic_health::pb::health_check_response::ServingStatus;
+use tonic_health::pb::health_client::HealthClient;
+use tonic_health::pb::HealthCheckRequest;
+use tower::service_fn;
+use crate::cli::HealthcheckArgs;
+use crate::config::Config;
+use crate::server::{ListenAddr, ListenerConfig, TlsSettings};
+
+/// Where the health check connects to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HealthcheckTarget {
+    /// `http://` or `https://` URI.
+    Uri(String),
+    Unix { path: PathBuf, tls: bool },
+    /// `/readyz` URL of the plaintext admin server.
+    Admin(String),
+}
+
+impl HealthcheckTarget {
+    /// Parses `--addr`, a URI defaulting to `http://`, or `unix:///path`.
+    pub fn parse(addr: &str) -> Self {
+        match addr.strip_prefix("unix://") {
+            Some(path) => HealthcheckTarget::Unix { path: PathBuf::from(path), tls: false },
+            None if addr.contains("://") => HealthcheckTarget::Uri(addr.to_string()),
+            None => HealthcheckTarget::Uri(format!("http://{}", addr)),
+        }
+    }
+
+    /// Parses the `--addr` of the admin server, defaulting to `http://`.
+    pub fn parse_admin(addr: &str) -> Self {
+        let addr = addr.trim_end_matches('/');
+        match addr.contains("://") {
+            true => HealthcheckTarget::Admin(format!("{}/readyz", addr)),
+            false => HealthcheckTarget::Admin(format!("http://{}/readyz", addr)),
+        }
+    }
+
+    /// The admin server's `/readyz` over loopback, which needs neither the TLS
+    /// material nor a client certificate.
+    pub fn admin_from_config(config: &Config) -> Self {
+        let host = if config.server.enable_ipv6 { "[::1]" } else { "127.0.0.1" };
+        HealthcheckTarget::Admin(format!("http://{}:{}/readyz", host, config.metrics.port))
+    }
+
+    /// Picks the first plaintext listener, so the check needs no client
+    /// certificate, and falls back to the first listener. Wildcard addresses
+    /// are checked over loopback.
+    pub fn from_config(config: &Config) -> Result<Self, Box<dyn std::error::Error>> {
+        let listeners = ListenerConfig::from_options(&config.server)?;
+        let listener = listeners
+            .iter()
+            .find(|listener| listener.tls == Some(false))
+            .or_else(|| listeners.first())
+            .ok_or("No listener configured")?;
+
+        let tls = listener.tls.unwrap_or(true) && TlsSettings::from_options(&config.tls).is_some();
+
+        Ok(match &listener.addr {
+            ListenAddr::Tcp { addr, .. } => {
+                let mut addr = *addr;
+                if addr.ip().is_unspecified() {
+                    addr.set_ip(match addr.ip() {
+                        IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::LOCALHOST),
+                        IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::LOCALHOST),
+                    });
+                }
+                let scheme = if tls { "https" } else { "http" };
+                HealthcheckTarget::Uri(format!("{}://{}", scheme, addr))
+            }
+            ListenAddr::Unix { path, .. } => HealthcheckTarget::Unix { path: path.clone(), tls },
+        })
+    }
+}
+
+/// Asks the server's health service for the status of `args.service`.
+pub async fn healthcheck(
+    target: &HealthcheckTarget,
+    args: &HealthcheckArgs,
+) -> Result<ServingStatus, Box<dyn std::error::Error>> {
+    let timeout = Duration::from_secs(args.timeout);
+
+    let (uri, tls) = match target {
+        HealthcheckTarget::Admin(url) => return readyz(url, timeout).await,
+        HealthcheckTarget::Uri(uri) => (uri.clone(), uri.starts_with("https://")),
+        // The authority is ignored by the connector, but used as the TLS server name
+        HealthcheckTarget::Unix { tls, .. } => {
+            let scheme = if *tls { "https" } else { "http" };
+            (format!("{}://localhost", scheme), *tls)
+        }
+    };
+
+    let mut endpoint = Endpoint::from_shared(uri)?.connect_timeout(timeout).timeout(timeout);
+    if tls {
+        let mut tls_config = ClientTlsConfig::new();
+        if let Some(ca_cert) = &args.ca_cert {
+            tls_config = tls_config.ca_certificate(Certificate::from_pem(std::fs::read(ca_cert)?));
+        }
+        if let Some(domain) = &args.domain {
+            tls_config = tls_config.domain_name(domain);
+        }
+        endpoint = endpoint.tls_config(tls_config)?;
+    }
+
+    let channel: Channel = match target {
+        HealthcheckTarget::Uri(_) | HealthcheckTarget::Admin(_) => endpoint.connect().await?,
+        HealthcheckTarget::Unix { path, .. } => {
+            let path = path.clone();
+            endpoint
+                .connect_with_connector(service_fn(move |_: Uri| UnixStream::connect(path.clone())))
+                .await?
+        }
+    };
+
+    let response = HealthClient::new(channel)
+        .check(HealthCheckRequest { service: args.service.clone() })
+        .await?;
+
+    Ok(response.into_inner().status())
+}
+
+/// Maps the answer of the admin server's `/readyz` to a serving status.
+async fn readyz(url: &str, timeout: Duration) -> Result<ServingStatus, Box<dyn std::error::Error>> {
+    let response = reqwest::Client::new().get(url).timeout(timeout).send().await?;
+
+    match response.status() {
+        reqwest::StatusCode::OK => Ok(ServingStatus::Serving),
+        reqwest::StatusCode::SERVICE_UNAVAILABLE => Ok(ServingStatus::NotServing),
+        status => Err(format!("{} answered {}", url, status).into()),
+    }
+}
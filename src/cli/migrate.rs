@@ -0,0 +1,20 @@
+use crate::cli::MigrateAction;
+use crate::database;
+
+pub async fn migrate(database_url: &str, action: MigrateAction) -> Result<(), sqlx::Error> {
+    match action {
+        MigrateAction::Run => database::check_for_migrations(database_url).await?,
+        MigrateAction::Status => {
+            for migration in database::migration_status(database_url).await? {
+                let status = if migration.applied { "applied" } else { "pending" };
+                println!("{}  {:<8} {}", migration.version, status, migration.description);
+            }
+        }
+        MigrateAction::Revert => match database::revert_last_migration(database_url).await? {
+            Some(version) => println!("Reverted migration {}", version),
+            None => println!("No migration to revert"),
+        },
+    }
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+mod api_keys;
+mod cli;
+mod descriptors;
+mod healthcheck;
+mod migrate;
+mod slo;
+
+pub use self::api_keys::*;
+pub use self::cli::*;
+pub use self::descriptors::*;
+pub use self::healthcheck::*;
+pub use self::migrate::*;
+pub use self::slo::*;
@@ -0,0 +1,166 @@
+use serde_json::{json, Value};
+use yaml_rust::{Yaml, YamlEmitter};
+use crate::cli::GenerateSloArgs;
+use crate::config::Config;
+use crate::server::{AppBuilder, Objectives};
+
+/// Long and short windows of the burn rate alerts, the burn rate and the
+/// severity, as recommended by the Google SRE workbook for a 30 day budget.
+const BURN_RATES: &[(&str, &str, f64, &str)] = &[
+    ("1h", "5m", 14.4, "page"),
+    ("6h", "30m", 6.0, "page"),
+    ("1d", "2h", 3.0, "ticket"),
+    ("3d", "6h", 1.0, "ticket"),
+];
+
+/// Writes `slo-rules.yml` and `slo-dashboard.json` for the objectives of the served methods.
+pub fn generate_slo(config: &Config, app: &AppBuilder, args: &GenerateSloArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let objectives = Objectives::new(&config.slo, &app.methods()?)?;
+    if objectives.iter().next().is_none() {
+        return Err("No method has an objective, set slo.default_objective or slo.methods".into());
+    }
+
+    std::fs::create_dir_all(&args.output_dir)?;
+    let rules = args.output_dir.join("slo-rules.yml");
+    std::fs::write(&rules, prometheus_rules(&objectives)?)?;
+    let dashboard = args.output_dir.join("slo-dashboard.json");
+    let mut json = serde_json::to_vec_pretty(&grafana_dashboard(&config.otel.service_name, &objectives))?;
+    json.push(b'\n');
+    std::fs::write(&dashboard, json)?;
+
+    tracing::info!("Wrote {} and {}", rules.display(), dashboard.display());
+    Ok(())
+}
+
+/// Multiwindow burn rate alerts on the error and latency budgets of each method.
+pub fn prometheus_rules(objectives: &Objectives) -> Result<String, Box<dyn std::error::Error>> {
+    let mut rules = Vec::new();
+    for (method, objective) in objectives.iter() {
+        let budgets = [
+            ("SloErrorBudgetBurn", "slo_errors_total", objective.error_budget(), "failing"),
+            ("SloLatencyBudgetBurn", "slo_slow_requests_total", objective.latency_budget(), "slow"),
+        ];
+        for (alert, metric, budget, problem) in budgets {
+            let Some(budget) = budget else { continue };
+            for (long, short, burn_rate, severity) in BURN_RATES {
+                let threshold = format!("{} * {}", burn_rate, number(budget));
+                rules.push(json!({
+                    "alert": alert,
+                    "expr": format!(
+                        "{} > {}\nand\n{} > {}",
+                        ratio(metric, method, long), threshold, ratio(metric, method, short), threshold,
+                    ),
+                    "labels": { "severity": severity, "method": method },
+                    "annotations": {
+                        "summary": format!("{} is {} too often", method, problem),
+                        "description": format!(
+                            "{} of the requests to {} were {} over the last {}, burning its budget {}x faster than allowed",
+                            "{{ $value | humanizePercentage }}", method, problem, long, burn_rate,
+                        ),
+                    },
+                }));
+            }
+        }
+    }
+
+    let groups = json!({ "groups": [{ "name": "slo", "rules": rules }] });
+    let mut out = String::new();
+    YamlEmitter::new(&mut out).dump(&yaml(&groups))?;
+    // The emitter starts documents with `---` and doesn't end them with a newline
+    out.push('\n');
+    Ok(out)
+}
+
+/// One row per method with its success rate and share of fast requests against their objectives.
+pub fn grafana_dashboard(service_name: &str, objectives: &Objectives) -> Value {
+    let mut panels = Vec::new();
+    let mut y = 0;
+    for (method, objective) in objectives.iter() {
+        panels.push(json!({
+            "type": "row",
+            "title": method,
+            "gridPos": { "h": 1, "w": 24, "x": 0, "y": y },
+            "collapsed": false,
+            "panels": [],
+        }));
+        y += 1;
+
+        let mut x = 0;
+        if let Some(success_rate) = objective.success_rate {
+            panels.push(ratio_panel("Success rate", "slo_errors_total", method, success_rate, x, y));
+            x += 12;
+        }
+        if let Some((threshold, percentile)) = objective.latency {
+            let title = format!("Requests within {}ms", threshold.as_millis());
+            panels.push(ratio_panel(&title, "slo_slow_requests_total", method, percentile, x, y));
+        }
+        y += 8;
+    }
+
+    json!({
+        "title": format!("{} SLOs", service_name),
+        "uid": format!("{}-slo", service_name),
+        "tags": ["slo"],
+        "schemaVersion": 39,
+        "time": { "from": "now-24h", "to": "now" },
+        "templating": {
+            "list": [{ "name": "datasource", "label": "Data source", "type": "datasource", "query": "prometheus" }],
+        },
+        "panels": panels,
+    })
+}
+
+/// Share of the requests counted by `metric`, inverted to compare with `target`.
+fn ratio_panel(title: &str, metric: &str, method: &str, target: f64, x: u32, y: u32) -> Value {
+    json!({
+        "type": "timeseries",
+        "title": title,
+        "datasource": { "type": "prometheus", "uid": "${datasource}" },
+        "gridPos": { "h": 8, "w": 12, "x": x, "y": y },
+        "targets": [{
+            "expr": format!("1 - {}", ratio(metric, method, "$__rate_interval")),
+            "legendFormat": title,
+        }],
+        "fieldConfig": {
+            "defaults": {
+                "unit": "percentunit",
+                "custom": { "thresholdsStyle": { "mode": "line" } },
+                "thresholds": {
+                    "mode": "absolute",
+                    "steps": [
+                        { "color": "red", "value": null },
+                        { "color": "green", "value": (target * 1000.0).round() / 100_000.0 },
+                    ],
+                },
+            },
+            "overrides": [],
+        },
+    })
+}
+
+fn ratio(metric: &str, method: &str, window: &str) -> String {
+    format!(
+        "sum(rate({}{{method=\"{}\"}}[{}])) / sum(rate(slo_requests_total{{method=\"{}\"}}[{}]))",
+        metric, method, window, method, window,
+    )
+}
+
+/// Formats a budget without the rounding noise of `1 - 99.9 / 100`.
+fn number(value: f64) -> String {
+    let formatted = format!("{:.6}", value);
+    formatted.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+fn yaml(value: &Value) -> Yaml {
+    match value {
+        Value::Null => Yaml::Null,
+        Value::Bool(value) => Yaml::Boolean(*value),
+        Value::Number(value) => match value.as_i64() {
+            Some(value) => Yaml::Integer(value),
+            None => Yaml::Real(value.to_string()),
+        },
+        Value::String(value) => Yaml::String(value.clone()),
+        Value::Array(values) => Yaml::Array(values.iter().map(yaml).collect()),
+        Value::Object(values) => Yaml::Hash(values.iter().map(|(key, value)| (Yaml::String(key.clone()), yaml(value))).collect()),
+    }
+}
@@ -0,0 +1,133 @@
+use chrono::{DateTime, Utc};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+use crate::database::{PgPool, PgPooledConnection};
+use crate::errors::ApiError;
+
+/// Keys start with this, to make leaked keys easy to spot.
+const KEY_PREFIX: &str = "ak_";
+/// Characters of the key kept in clear, prefix included.
+const DISPLAYED_CHARS: usize = 11;
+
+const COLUMNS: &str = "id, prefix, key_hash, owner, scopes, expires_at, last_used_at, revoked_at, created_at";
+
+/// A row of `api_keys`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ApiKeyRecord {
+    pub id: Uuid,
+    pub prefix: String,
+    pub key_hash: String,
+    pub owner: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ApiKeyRecord {
+    /// Neither revoked nor expired at `now`.
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at.map_or(true, |expires_at| expires_at > now)
+    }
+}
+
+/// Keys are random, so a plain SHA-256 is enough to keep them out of the database.
+pub fn hash_api_key(key: &str) -> String {
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+/// Stores a new random key and returns it along with its row. The key can't
+/// be recovered afterwards.
+#[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+pub async fn insert_api_key(
+    conn: &mut PgPooledConnection,
+    owner: &str,
+    scopes: &[String],
+    expires_at: Option<DateTime<Utc>>,
+) -> Result<(ApiKeyRecord, String), ApiError> {
+    let mut secret = [0u8; 32];
+    rand::rngs::OsRng.fill_bytes(&mut secret);
+    let key = format!("{}{}", KEY_PREFIX, hex::encode(secret));
+
+    let record = sqlx::query_as::<_, ApiKeyRecord>(&format!(
+        "INSERT INTO api_keys (prefix, key_hash, owner, scopes, expires_at) VALUES ($1, $2, $3, $4, $5) RETURNING {}",
+        COLUMNS
+    ))
+        .bind(&key[..DISPLAYED_CHARS])
+        .bind(hash_api_key(&key))
+        .bind(owner)
+        .bind(scopes)
+        .bind(expires_at)
+        .fetch_one(&mut **conn)
+        .await?;
+
+    Ok((record, key))
+}
+
+/// Keys of `owner`, or of everyone, newest first.
+#[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+pub async fn list_api_keys(
+    conn: &mut PgPooledConnection,
+    owner: Option<&str>,
+    include_revoked: bool,
+) -> Result<Vec<ApiKeyRecord>, ApiError> {
+    let records = sqlx::query_as::<_, ApiKeyRecord>(&format!(
+        "SELECT {} FROM api_keys WHERE ($1::TEXT IS NULL OR owner = $1) AND ($2 OR revoked_at IS NULL) ORDER BY created_at DESC",
+        COLUMNS
+    ))
+        .bind(owner)
+        .bind(include_revoked)
+        .fetch_all(&mut **conn)
+        .await?;
+
+    Ok(records)
+}
+
+/// Revoking a key twice keeps the first revocation time.
+#[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+pub async fn revoke_api_key(conn: &mut PgPooledConnection, id: Uuid) -> Result<ApiKeyRecord, ApiError> {
+    sqlx::query_as::<_, ApiKeyRecord>(&format!(
+        "UPDATE api_keys SET revoked_at = COALESCE(revoked_at, current_timestamp) WHERE id = $1 RETURNING {}",
+        COLUMNS
+    ))
+        .bind(id)
+        .fetch_optional(&mut **conn)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("api key {}", id)))
+}
+
+#[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+pub async fn get_api_key(conn: &mut PgPooledConnection, id: Uuid) -> Result<ApiKeyRecord, ApiError> {
+    sqlx::query_as::<_, ApiKeyRecord>(&format!("SELECT {} FROM api_keys WHERE id = $1", COLUMNS))
+        .bind(id)
+        .fetch_optional(&mut **conn)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("api key {}", id)))
+}
+
+#[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+pub async fn find_api_key(conn: &mut PgPooledConnection, key_hash: &str) -> Result<Option<ApiKeyRecord>, ApiError> {
+    let record = sqlx::query_as::<_, ApiKeyRecord>(&format!("SELECT {} FROM api_keys WHERE key_hash = $1", COLUMNS))
+        .bind(key_hash)
+        .fetch_optional(&mut **conn)
+        .await?;
+
+    Ok(record)
+}
+
+/// Records a use of the key. Writes at most once a minute per key.
+#[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+pub async fn touch_api_key(pool: &PgPool, id: Uuid) -> Result<(), ApiError> {
+    sqlx::query(
+        "UPDATE api_keys SET last_used_at = current_timestamp \
+         WHERE id = $1 AND (last_used_at IS NULL OR last_used_at < current_timestamp - INTERVAL '1 minute')",
+    )
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
@@ -1,31 +1,27 @@
-use std::env;
-use std::time::Duration;
+use std::collections::HashSet;
+use std::ops::{Deref, DerefMut};
+use std::time::{Duration, Instant};
+use sqlx::pool::PoolConnection;
 use sqlx::postgres::PgPoolOptions;
-use sqlx::{Connection, PgConnection, Pool, Postgres};
-use sqlx::migrate::MigrateDatabase;
-use tonic::Status;
+use sqlx::{Connection, Executor, PgConnection, Pool, Postgres};
+use sqlx::migrate::{Migrate, MigrateDatabase, Migrator};
+use crate::config::DatabaseOptions;
+use crate::errors::ApiError;
+use crate::metrics::metrics;
+use crate::utils::deadline::Deadline;
 
-pub type PgPooledConnection = sqlx::pool::PoolConnection<Postgres>;
 pub type PgPool = Pool<Postgres>;
 
-pub async fn connect(database_url: &str) -> Result<PgPool, sqlx::Error> {
-    log::info!("Connecting to database: {}", database_url);
+static MIGRATOR: Migrator = sqlx::migrate!();
+
+pub async fn connect(options: &DatabaseOptions) -> Result<PgPool, sqlx::Error> {
+    tracing::info!("Connecting to database...");
 
     let pool = PgPoolOptions::new()
-        .min_connections(
-            env::var("DATABASE_MIN_CONNECTIONS")
-                .ok()
-                .and_then(|x| x.parse().ok())
-                .unwrap_or(0),
-        )
-        .max_connections(
-            env::var("DATABASE_MAX_CONNECTIONS")
-                .ok()
-                .and_then(|x| x.parse().ok())
-                .unwrap_or(16),
-        )
+        .min_connections(options.min_connections)
+        .max_connections(options.max_connections)
         .max_lifetime(Some(Duration::from_secs(60 * 60)))
-        .connect(database_url)
+        .connect(&options.url)
         .await?;
 
     Ok(pool)
@@ -33,22 +29,173 @@ pub async fn connect(database_url: &str) -> Result<PgPool, sqlx::Error> {
 
 pub async fn check_for_migrations(database_url: &str) -> Result<(), sqlx::Error> {
     if !Postgres::database_exists(database_url).await? {
-        log::info!("Database does not exist, creating it...");
+        tracing::info!("Database does not exist, creating it...");
         Postgres::create_database(database_url).await?;
     }
 
-    log::info!("Running migrations...");
+    tracing::info!("Running migrations...");
 
     let mut conn: PgConnection = PgConnection::connect(database_url).await?;
+    MIGRATOR.run(&mut conn).await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub description: String,
+    pub applied: bool,
+}
 
-    sqlx::migrate!()
-        .run(&mut conn)
-        .await
-        .expect("Failed to run migrations");
+/// Lists the migrations, all of them pending when the database doesn't exist yet.
+pub async fn migration_status(database_url: &str) -> Result<Vec<MigrationStatus>, sqlx::Error> {
+    let mut applied = HashSet::new();
+    if Postgres::database_exists(database_url).await? {
+        let mut conn: PgConnection = PgConnection::connect(database_url).await?;
+        conn.ensure_migrations_table().await?;
+        applied.extend(conn.list_applied_migrations().await?.into_iter().map(|migration| migration.version));
+    }
+
+    Ok(MIGRATOR
+        .iter()
+        .filter(|migration| !migration.migration_type.is_down_migration())
+        .map(|migration| MigrationStatus {
+            version: migration.version,
+            description: migration.description.to_string(),
+            applied: applied.contains(&migration.version),
+        })
+        .collect())
+}
+
+/// Reverts the most recently applied migration and returns its version,
+/// or `None` when no migration is applied.
+pub async fn revert_last_migration(database_url: &str) -> Result<Option<i64>, sqlx::Error> {
+    if !Postgres::database_exists(database_url).await? {
+        return Ok(None);
+    }
 
+    let mut conn: PgConnection = PgConnection::connect(database_url).await?;
+    conn.ensure_migrations_table().await?;
+
+    let mut applied = conn
+        .list_applied_migrations()
+        .await?
+        .into_iter()
+        .map(|migration| migration.version)
+        .collect::<Vec<_>>();
+    applied.sort_unstable();
+
+    let last = match applied.pop() {
+        Some(last) => last,
+        None => return Ok(None),
+    };
+
+    let reversible = MIGRATOR
+        .iter()
+        .any(|migration| migration.version == last && migration.migration_type.is_down_migration());
+    if !reversible {
+        return Err(sqlx::Error::Configuration(
+            format!("migration {} has no down migration and cannot be reverted", last).into(),
+        ));
+    }
+
+    tracing::info!("Reverting migration {}...", last);
+    MIGRATOR.undo(&mut conn, applied.last().copied().unwrap_or(0)).await?;
+
+    Ok(Some(last))
+}
+
+/// Round-trips a `SELECT 1` to check that Postgres is reachable.
+#[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+pub async fn ping(pool: &PgPool) -> Result<(), sqlx::Error> {
+    sqlx::query("SELECT 1").execute(pool).await?;
     Ok(())
 }
 
-pub async fn get_connection(pool: &PgPool) -> Result<PgPooledConnection, Status> {
-    pool.acquire().await.map_err(|_| Status::internal("Failed to acquire connection"))
+/// Acquires a connection for a request. The deadline bounds the wait on the
+/// pool and becomes the connection's `statement_timeout` until it is released.
+#[tracing::instrument(skip_all)]
+pub async fn get_connection(pool: &PgPool, deadline: Deadline) -> Result<PgPooledConnection, ApiError> {
+    let wait = AcquireWait::start();
+    let acquired = deadline.run("waiting for a database connection", pool.acquire()).await;
+    drop(wait);
+    record_pool_metrics(pool);
+    let mut conn = PgPooledConnection { conn: Some(acquired??), timeout_set: false };
+
+    if let Some(remaining) = deadline.remaining() {
+        let statement = format!("SET statement_timeout = {}", remaining.as_millis().max(1));
+        // Marked first, a timeout cut short may still have been applied
+        conn.timeout_set = true;
+        deadline.run("setting the statement timeout", conn.execute(statement.as_str())).await??;
+    }
+
+    Ok(conn)
+}
+
+/// A pooled connection acquired by [`get_connection`]. The `statement_timeout`
+/// of a request mustn't leak into the next one, so a connection that was given
+/// one resets it before going back to the pool.
+#[derive(Debug)]
+pub struct PgPooledConnection {
+    conn: Option<PoolConnection<Postgres>>,
+    timeout_set: bool,
+}
+
+impl Deref for PgPooledConnection {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &PgConnection {
+        self.conn.as_ref().expect("the connection is only taken on drop")
+    }
+}
+
+impl DerefMut for PgPooledConnection {
+    fn deref_mut(&mut self) -> &mut PgConnection {
+        self.conn.as_mut().expect("the connection is only taken on drop")
+    }
+}
+
+impl Drop for PgPooledConnection {
+    fn drop(&mut self) {
+        let mut conn = match self.conn.take() {
+            Some(conn) if self.timeout_set => conn,
+            _ => return,
+        };
+        match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => {
+                runtime.spawn(async move {
+                    if conn.execute("RESET statement_timeout").await.is_err() {
+                        let _ = conn.close().await;
+                    }
+                });
+            }
+            // Without a runtime the reset can't run, the connection is closed instead
+            Err(_) => drop(conn.detach()),
+        }
+    }
+}
+
+/// Publishes the size and idle connections of the pool. Refreshed on every
+/// acquire and health check.
+pub fn record_pool_metrics(pool: &PgPool) {
+    metrics().db_pool_connections.set(pool.size().into());
+    metrics().db_pool_idle_connections.set(pool.num_idle() as i64);
+}
+
+/// Counts a pending acquire and records how long it waited, abandoned waits included.
+struct AcquireWait(Instant);
+
+impl AcquireWait {
+    fn start() -> Self {
+        metrics().db_pool_pending_acquires.inc();
+        AcquireWait(Instant::now())
+    }
+}
+
+impl Drop for AcquireWait {
+    fn drop(&mut self) {
+        metrics().db_pool_pending_acquires.dec();
+        metrics().db_pool_acquire_duration.observe(self.0.elapsed().as_secs_f64());
+    }
 }
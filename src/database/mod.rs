@@ -1,3 +1,5 @@
+mod api_keys;
+pub use api_keys::*;
 mod database;
 pub use database::*;
 mod redis;
@@ -1,11 +1,16 @@
 use std::future::Future;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use crate::errors::ApiError;
+use crate::metrics::{metrics, RedisLabels};
 use crate::report_error;
-use redis::{Commands};
+use crate::utils::deadline::Deadline;
+use redis::aio::MultiplexedConnection;
+use redis::{AsyncCommands, RedisResult};
+use tokio::sync::Mutex;
 use tonic::{Response, Status};
 use tonic::metadata::MetadataValue;
 
@@ -13,9 +18,12 @@ pub fn connect_redis(redis_url: String) -> Result<redis::Client, redis::RedisErr
     redis::Client::open(redis_url)
 }
 
+/// Redis cache shared by the services. Commands go through a single
+/// multiplexed connection, opened on first use and again after it fails.
 #[derive(Clone)]
 pub struct CacheClient {
     client: Arc<redis::Client>,
+    connection: Arc<Mutex<Option<MultiplexedConnection>>>,
     cache_ttl: u64,
 }
 
@@ -23,24 +31,94 @@ impl CacheClient {
     pub fn new(client: redis::Client, cache_ttl: u64) -> Self {
         CacheClient {
             client: Arc::new(client),
+            connection: Arc::new(Mutex::new(None)),
             cache_ttl,
         }
     }
 
-    fn generate_cache_key(&self, method_name: &str, request: &impl Serialize) -> String {
-        format!("{}:{}", method_name, serde_json::to_string(request).unwrap())
+    /// Round-trips a `PING` to check that Redis is reachable.
+    pub async fn ping(&self, timeout: Duration) -> Result<(), ApiError> {
+        self.query(Deadline::after(timeout), "PING", "pinging Redis", |mut conn| async move {
+            redis::cmd("PING").query_async::<_, String>(&mut conn).await
+        }).await?;
+
+        Ok(())
     }
 
-    async fn get_cache<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, ApiError> {
-        let mut conn = self.client.get_connection().map_err(|e| {
-            report_error(&e);
-            ApiError::CacheError
-        })?;
+    async fn connection(&self, deadline: Deadline) -> Result<MultiplexedConnection, ApiError> {
+        let mut connection = deadline.run("waiting for the Redis connection", self.connection.lock()).await?;
+        if let Some(conn) = connection.as_ref() {
+            return Ok(conn.clone());
+        }
 
-        let data: Option<Vec<u8>> = conn.get(key).map_err(|e| {
-            report_error(&e);
-            ApiError::CacheError
-        })?;
+        let conn = deadline
+            .run("connecting to Redis", self.client.get_multiplexed_tokio_connection())
+            .await?
+            .map_err(|e| {
+                report_error(&e);
+                ApiError::RedisConnectionFailure
+            })?;
+        *connection = Some(conn.clone());
+
+        Ok(conn)
+    }
+
+    /// Runs a command, capped by `deadline`, and records its latency under
+    /// `name`. `operation` names it in the `DEADLINE_EXCEEDED` error.
+    async fn query<T, F, Fut>(&self, deadline: Deadline, name: &'static str, operation: &str, command: F) -> Result<T, ApiError>
+    where
+        F: FnOnce(MultiplexedConnection) -> Fut,
+        Fut: Future<Output = RedisResult<T>>,
+    {
+        let started = Instant::now();
+        let result = async {
+            let conn = self.connection(deadline).await?;
+
+            match deadline.run(operation, command(conn)).await? {
+                Ok(value) => Ok(value),
+                Err(e) => {
+                    if e.is_io_error() || e.is_connection_dropped() {
+                        // Reconnect on the next command
+                        *self.connection.lock().await = None;
+                    }
+                    report_error(&e);
+                    Err(ApiError::CacheError)
+                }
+            }
+        }.await;
+
+        let labels = RedisLabels { command: name, result: if result.is_ok() { "success" } else { "failure" } };
+        metrics().redis_command_duration.get_or_create(&labels).observe(started.elapsed().as_secs_f64());
+        result
+    }
+
+    /// Counts a hit against `current` and reads `previous`, the counters of the
+    /// current and previous windows of a rate limit. `current` expires once it
+    /// can no longer be read as a previous window.
+    pub(crate) async fn count_hit(&self, deadline: Deadline, current: &str, previous: &str, window: Duration) -> Result<(u64, u64), ApiError> {
+        let (current_hits, previous_hits): (u64, Option<u64>) = self.query(deadline, "rate_limit_hit", "counting a rate limited request", |mut conn| async move {
+            redis::pipe()
+                .atomic()
+                .incr(current, 1)
+                .pexpire(current, 2 * window.as_millis() as i64)
+                .ignore()
+                .get(previous)
+                .query_async(&mut conn)
+                .await
+        }).await?;
+
+        Ok((current_hits, previous_hits.unwrap_or(0)))
+    }
+
+    pub(crate) fn generate_cache_key(&self, method_name: &str, request: &impl Serialize) -> String {
+        format!("{}:{}", method_name, serde_json::to_string(request).unwrap())
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "redis"))]
+    pub(crate) async fn get_cache<T: DeserializeOwned>(&self, deadline: Deadline, key: &str) -> Result<Option<T>, ApiError> {
+        let data: Option<Vec<u8>> = self.query(deadline, "GET", "reading the cache", |mut conn| async move {
+            conn.get(key).await
+        }).await?;
 
         if let Some(data) = data {
             let result = serde_json::from_slice(&data).map_err(|e| {
@@ -48,83 +126,72 @@ impl CacheClient {
                 ApiError::CacheError
             })?;
 
-            log::debug!("Cache hit for key: {}", key);
+            tracing::debug!("Cache hit for key: {}", key);
 
             Ok(Some(result))
         } else {
 
-            log::debug!("Cache miss for key: {}", key);
+            tracing::debug!("Cache miss for key: {}", key);
 
             Ok(None)
         }
     }
 
-    async fn set_cache<T: Serialize>(&self, key: &str, value: &T) -> Result<(), ApiError> {
-        let mut conn = self.client.get_connection().map_err(|e| {
-            report_error(&e);
-            ApiError::CacheError
-        })?;
+    pub(crate) async fn set_cache<T: Serialize>(&self, deadline: Deadline, key: &str, value: &T) -> Result<(), ApiError> {
+        self.set_cache_for(deadline, key, value, self.cache_ttl).await
+    }
 
+    /// Like [`CacheClient::set_cache`], with an entry kept for `cache_ttl` seconds.
+    #[tracing::instrument(skip_all, fields(db.system = "redis"))]
+    pub(crate) async fn set_cache_for<T: Serialize>(&self, deadline: Deadline, key: &str, value: &T, cache_ttl: u64) -> Result<(), ApiError> {
         let data = serde_json::to_vec(value).map_err(|e| {
             report_error(&e);
             ApiError::CacheError
         })?;
 
-        conn.set_ex(key, data, self.cache_ttl).map_err(|e| {
-            report_error(&e);
-            ApiError::CacheError
-        })?;
+        self.query(deadline, "SETEX", "writing the cache", |mut conn| async move {
+            conn.set_ex::<_, _, ()>(key, data, cache_ttl).await
+        }).await?;
 
-        log::debug!("Cache set for key: {}", key);
+        tracing::debug!("Cache set for key: {}", key);
 
         Ok(())
     }
 
-    pub(crate) async fn invalid_cache(&self, method_name: &str, request: &impl Serialize) -> Result<(), ApiError> {
+    pub(crate) async fn invalid_cache(&self, deadline: Deadline, method_name: &str, request: &impl Serialize) -> Result<(), ApiError> {
         let cache_key = self.generate_cache_key(method_name, request);
-        let mut conn = self.client.get_connection().map_err(|e| {
-            report_error(&e);
-            ApiError::CacheError
-        })?;
-
-        conn.del(cache_key).map_err(|e| {
-            report_error(&e);
-            ApiError::CacheError
-        })?;
 
-        Ok(())
+        self.query(deadline, "DEL", "invalidating the cache", |mut conn| async move {
+            conn.del::<_, ()>(cache_key).await
+        }).await
     }
 
-    pub(crate) async fn invalidate_related_cache_keys(&self, organizer_key: String) -> Result<(), ApiError> {
-        let mut conn = self.client.get_connection().map_err(|e| {
-            report_error(&e);
-            ApiError::CacheError
-        })?;
-
+    #[allow(dead_code)]
+    pub(crate) async fn invalidate_related_cache_keys(&self, deadline: Deadline, organizer_key: String) -> Result<(), ApiError> {
         let keys_to_invalidate = vec![
             "list_*:{\"filters\":{*\"organizerKey\":\"".to_string() + &organizer_key + "\"*}*",
         ];
 
-        log::debug!("Invalidating cache keys: {:?}", keys_to_invalidate);
+        tracing::debug!("Invalidating cache keys: {:?}", keys_to_invalidate);
 
         for key_pattern in keys_to_invalidate {
-            let keys: Vec<String> = conn.keys(key_pattern).map_err(|e| {
-                report_error(&e);
-                ApiError::CacheError
-            })?;
+            let keys: Vec<String> = self.query(deadline, "KEYS", "listing cache keys", |mut conn| async move {
+                conn.keys(key_pattern).await
+            }).await?;
             for key in keys {
-                conn.del(&key).map_err(|e| {
-                    report_error(&e);
-                    ApiError::CacheError
-                })?;
+                self.query(deadline, "DEL", "invalidating the cache", |mut conn| async move {
+                    conn.del::<_, ()>(key).await
+                }).await?;
             }
         }
 
         Ok(())
     }
 
+    #[tracing::instrument(skip_all, fields(method = method_name, cache = tracing::field::Empty))]
     pub(crate) async fn handle_cache<T, F, Fut>(
         &self,
+        deadline: Deadline,
         method_name: &str,
         request: &impl Serialize,
         call: F,
@@ -139,8 +206,14 @@ impl CacheClient {
 
         let cache_key = self.generate_cache_key(method_name, request);
 
+        let cached = self.get_cache::<T>(deadline, &cache_key).await.inspect_err(|_| {
+            metrics().cache_lookup(method_name, "error");
+        })?;
+
         // Cache hit
-        if let Some(cached_response) = self.get_cache::<T>(&cache_key).await? {
+        if let Some(cached_response) = cached {
+            tracing::Span::current().record("cache", "hit");
+            metrics().cache_lookup(method_name, "hit");
             let mut response = Response::new(cached_response);
             response.metadata_mut().insert(
                 CACHE_STATUS,
@@ -157,10 +230,12 @@ impl CacheClient {
         }
 
         // Cache miss
+        tracing::Span::current().record("cache", "miss");
+        metrics().cache_lookup(method_name, "miss");
         let mut response = call().await?;
 
         // Add response to cache
-        self.set_cache(&cache_key, response.get_ref()).await?;
+        self.set_cache(deadline, &cache_key, response.get_ref()).await?;
 
         response.metadata_mut().insert(
             CACHE_STATUS,
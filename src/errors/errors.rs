@@ -5,9 +5,17 @@ use serde_variant::to_variant_name;
 use thiserror::Error;
 use tonic_error::TonicError;
 use crate::report_error;
+use crate::server::RequestId;
 
 #[derive(Error, Debug, Serialize, Deserialize)]
-pub enum ValidationErrorMessage { }
+pub enum ValidationErrorMessage {
+    #[error("must be a UUID")]
+    NotUuid,
+    #[error("must be in the future")]
+    NotInFuture,
+    #[error("must not contain blank entries")]
+    BlankEntry,
+}
 
 #[derive(Error, Debug, Serialize, Deserialize)]
 pub enum ValidationErrorKind {
@@ -65,6 +73,16 @@ pub enum ApiError {
     ParsingError(String),
     #[error("validation error: {0}")]
     ValidationError(List<ValidationErrorKind>),
+    #[error("unauthenticated: {0}")]
+    Unauthenticated(String),
+    #[error("invalid token: {0}")]
+    InvalidToken(String),
+    #[error("permission denied: {0}")]
+    PermissionDenied(String),
+    #[error("deadline exceeded: {0}")]
+    DeadlineExceeded(String),
+    #[error("resource exhausted: {0}")]
+    ResourceExhausted(String),
 }
 
 impl ApiError {
@@ -78,15 +96,22 @@ impl ApiError {
             ApiError::AlreadyExists(_) => tonic::Code::AlreadyExists,
             ApiError::NotFound(_) => tonic::Code::NotFound,
             ApiError::ValidationError(_) => tonic::Code::InvalidArgument,
+            ApiError::Unauthenticated(_) => tonic::Code::Unauthenticated,
+            ApiError::InvalidToken(_) => tonic::Code::Unauthenticated,
+            ApiError::PermissionDenied(_) => tonic::Code::PermissionDenied,
+            ApiError::DeadlineExceeded(_) => tonic::Code::DeadlineExceeded,
+            ApiError::ResourceExhausted(_) => tonic::Code::ResourceExhausted,
             _ => tonic::Code::Internal,
         }
     }
 
+    /// ID of the request being handled, added to the `x-tonic-error` payload.
+    pub fn request_id(&self) -> Option<String> {
+        RequestId::current().map(|id| id.to_string())
+    }
+
     pub fn is_list(&self) -> bool {
-        match self {
-            ApiError::ValidationError(_) => true,
-            _ => false,
-        }
+        matches!(self, ApiError::ValidationError(_))
     }
 
     pub fn errors(&self) -> serde_json::Value {
@@ -111,6 +136,9 @@ impl ApiError {
     }
 }
 
+/// SQLSTATE of a statement cancelled by `statement_timeout`.
+const QUERY_CANCELED: &str = "57014";
+
 impl From<sqlx::Error> for ApiError {
     fn from(error: sqlx::Error) -> Self {
         match error {
@@ -121,6 +149,9 @@ impl From<sqlx::Error> for ApiError {
             sqlx::Error::Database(e) => {
                 if e.is_unique_violation() {
                     ApiError::AlreadyExists(e.message().to_string())
+                } else if e.code().as_deref() == Some(QUERY_CANCELED) {
+                    // Raised when the request's statement_timeout elapses
+                    ApiError::DeadlineExceeded(e.message().to_string())
                 } else {
                     report_error(&e);
                     ApiError::DatabaseError(e.message().to_string())
@@ -0,0 +1,371 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::body::Bytes;
+use axum::extract::{ConnectInfo, Path, Query};
+use axum::http::{HeaderMap, HeaderName, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{on, MethodFilter};
+use axum::{Json, Router};
+use tracing::{info, warn};
+use prost::Message;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use tonic::codegen::{http, Body as _};
+use tonic::body::BoxBody;
+use tonic::transport::server::TcpConnectInfo;
+use tower::{Service, ServiceExt};
+use crate::errors::ApiError;
+use crate::gateway::{http_bindings, HttpBinding};
+use crate::server::REQUEST_ID_HEADER;
+
+static CUSTOM_ERROR: &str = "x-tonic-error";
+
+type EncodeFn = dyn Fn(Value) -> Result<Vec<u8>, ApiError> + Send + Sync;
+type DecodeFn = dyn Fn(&[u8]) -> Result<Value, ApiError> + Send + Sync;
+
+/// Converts between the pbjson representation of a method's messages and protobuf.
+#[derive(Clone)]
+struct Transcoder {
+    encode: Arc<EncodeFn>,
+    decode: Arc<DecodeFn>,
+}
+
+struct Route {
+    binding: HttpBinding,
+    transcoder: Transcoder,
+}
+
+/// REST/JSON front for the gRPC services, built from the `google.api.http`
+/// options in their descriptor sets. Calls go through the same tonic
+/// `Routes` the gRPC server uses, optionally wrapped in tower layers.
+pub struct RestGateway {
+    bindings: Vec<HttpBinding>,
+    transcoders: HashMap<String, Transcoder>,
+}
+
+impl RestGateway {
+    pub fn new(descriptor_sets: &[&[u8]]) -> Result<Self, prost::DecodeError> {
+        let mut bindings = Vec::new();
+        for descriptor_set in descriptor_sets {
+            bindings.extend(http_bindings(descriptor_set)?);
+        }
+
+        Ok(RestGateway {
+            bindings,
+            transcoders: HashMap::new(),
+        })
+    }
+
+    /// Registers the message types of a unary RPC, e.g. `/echo.v1.EchoService/UnaryEcho`.
+    pub fn register<Req, Res>(mut self, grpc_method: &str) -> Self
+    where
+        Req: Message + DeserializeOwned + 'static,
+        Res: Message + Default + Serialize + 'static,
+    {
+        let transcoder = Transcoder {
+            encode: Arc::new(|value| {
+                let request: Req = serde_json::from_value(value)
+                    .map_err(|e| ApiError::InvalidRequest(e.to_string()))?;
+                Ok(request.encode_to_vec())
+            }),
+            decode: Arc::new(|bytes| {
+                let response = Res::decode(bytes)
+                    .map_err(|e| ApiError::ParsingError(e.to_string()))?;
+                serde_json::to_value(response)
+                    .map_err(|e| ApiError::ParsingError(e.to_string()))
+            }),
+        };
+
+        self.transcoders.insert(grpc_method.to_string(), transcoder);
+        self
+    }
+
+    pub fn into_router<S>(self, routes: S) -> Router
+    where
+        S: Service<http::Request<tonic::transport::Body>, Response = http::Response<BoxBody>> + Clone + Send + 'static,
+        S::Error: std::fmt::Display,
+        S::Future: Send,
+    {
+        let mut router = Router::new();
+
+        for binding in self.bindings {
+            let transcoder = match self.transcoders.get(&binding.grpc_method) {
+                Some(transcoder) => transcoder.clone(),
+                None => {
+                    warn!("No message types registered for {}, skipping its REST binding", binding.grpc_method);
+                    continue;
+                }
+            };
+
+            let filter = match method_filter(&binding.http_method) {
+                Some(filter) => filter,
+                None => {
+                    warn!("Unsupported HTTP method {} for {}", binding.http_method, binding.grpc_method);
+                    continue;
+                }
+            };
+
+            info!("REST {} {} -> {}", binding.http_method, binding.path, binding.grpc_method);
+            let path = axum_path(&binding.path);
+            let route = Arc::new(Route { binding, transcoder });
+            let routes = routes.clone();
+
+            // Tonic's `Routes` aren't `Sync`, so each request gets its own clone
+            let handler = move |peer: Option<ConnectInfo<SocketAddr>>, params: Option<Path<HashMap<String, String>>>, query: Query<HashMap<String, String>>, headers: HeaderMap, body: Bytes| {
+                transcode(route.clone(), routes.clone(), peer.map(|ConnectInfo(addr)| addr), params, query, headers, body)
+            };
+            router = router.route(&path, on(filter, handler));
+        }
+
+        router
+    }
+}
+
+async fn transcode<S>(
+    route: Arc<Route>,
+    routes: S,
+    peer: Option<SocketAddr>,
+    params: Option<Path<HashMap<String, String>>>,
+    Query(query): Query<HashMap<String, String>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response
+where
+    S: Service<http::Request<tonic::transport::Body>, Response = http::Response<BoxBody>>,
+    S::Error: std::fmt::Display,
+{
+    match call(&route, routes, peer, params.map(|Path(p)| p).unwrap_or_default(), query, &headers, body).await {
+        Ok((metadata, message)) => (StatusCode::OK, metadata, Json(message)).into_response(),
+        Err(status) => error_response(&status),
+    }
+}
+
+async fn call<S>(
+    route: &Route,
+    routes: S,
+    peer: Option<SocketAddr>,
+    params: HashMap<String, String>,
+    query: HashMap<String, String>,
+    headers: &HeaderMap,
+    body: Bytes,
+) -> Result<(HeaderMap, Value), tonic::Status>
+where
+    S: Service<http::Request<tonic::transport::Body>, Response = http::Response<BoxBody>>,
+    S::Error: std::fmt::Display,
+{
+    let message = build_message(&route.binding, params, query, &body)?;
+    let message = (route.transcoder.encode)(message)?;
+
+    let mut frame = Vec::with_capacity(message.len() + 5);
+    frame.push(0);
+    frame.extend_from_slice(&(message.len() as u32).to_be_bytes());
+    frame.extend_from_slice(&message);
+
+    let mut request = http::Request::builder()
+        .method(http::Method::POST)
+        .uri(route.binding.grpc_method.as_str())
+        .header("content-type", "application/grpc")
+        .header("te", "trailers");
+    for (name, value) in headers {
+        if forwarded_request_header(name) {
+            request = request.header(name.as_str(), value.as_bytes());
+        }
+    }
+    let mut request = request
+        .body(tonic::transport::Body::from(frame))
+        .map_err(|e| tonic::Status::internal(e.to_string()))?;
+    // Lets the middleware see the REST client the way it sees gRPC clients
+    request.extensions_mut().insert(TcpConnectInfo { local_addr: None, remote_addr: peer });
+
+    let response = routes
+        .oneshot(request)
+        .await
+        .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+    let (parts, mut body) = response.into_parts();
+    if let Some(status) = tonic::Status::from_header_map(&parts.headers) {
+        if status.code() != tonic::Code::Ok {
+            return Err(status);
+        }
+    }
+
+    let mut data = Vec::new();
+    while let Some(chunk) = body.data().await {
+        data.extend_from_slice(&chunk?);
+    }
+    if let Some(trailers) = body.trailers().await? {
+        if let Some(mut status) = tonic::Status::from_header_map(&trailers) {
+            if status.code() != tonic::Code::Ok {
+                // Only trailers-only responses carry the headers in the status
+                if let Some(Ok(id)) = parts.headers.get(REQUEST_ID_HEADER).map(|id| id.as_bytes().try_into()) {
+                    status.metadata_mut().insert(REQUEST_ID_HEADER, id);
+                }
+                return Err(status);
+            }
+        }
+    }
+
+    if data.len() < 5 || data[0] != 0 {
+        return Err(ApiError::ParsingError("unexpected gRPC response frame".to_string()).into());
+    }
+    let len = u32::from_be_bytes([data[1], data[2], data[3], data[4]]) as usize;
+    let message = data.get(5..5 + len)
+        .ok_or_else(|| ApiError::ParsingError("truncated gRPC response frame".to_string()))?;
+
+    Ok((response_headers(&parts.headers), (route.transcoder.decode)(message)?))
+}
+
+/// Assembles the pbjson request from the body, query string and path parameters.
+/// Path parameters win over the other two, and the query string is ignored
+/// when the whole body maps onto the request.
+fn build_message(
+    binding: &HttpBinding,
+    params: HashMap<String, String>,
+    query: HashMap<String, String>,
+    body: &[u8],
+) -> Result<Value, ApiError> {
+    let body = || -> Result<Value, ApiError> {
+        if body.is_empty() {
+            return Ok(Value::Object(Map::new()));
+        }
+        serde_json::from_slice(body).map_err(|e| ApiError::InvalidRequest(e.to_string()))
+    };
+
+    let mut message = match binding.body.as_deref() {
+        Some("*") => body()?,
+        Some(field) => {
+            let mut message = Value::Object(Map::new());
+            set_field(&mut message, field, body()?)?;
+            message
+        }
+        None => Value::Object(Map::new()),
+    };
+
+    if binding.body.as_deref() != Some("*") {
+        for (field, value) in query {
+            set_field(&mut message, &field, Value::String(value))?;
+        }
+    }
+
+    for (field, value) in params {
+        set_field(&mut message, &field, Value::String(value))?;
+    }
+
+    Ok(message)
+}
+
+/// Sets a possibly nested field such as `filters.organizer_key`.
+fn set_field(message: &mut Value, path: &str, value: Value) -> Result<(), ApiError> {
+    let mut current = message;
+    let mut fields = path.split('.').peekable();
+
+    while let Some(field) = fields.next() {
+        let object = current
+            .as_object_mut()
+            .ok_or_else(|| ApiError::InvalidRequest(format!("{} is not a message", path)))?;
+
+        if fields.peek().is_none() {
+            object.insert(field.to_string(), value);
+            return Ok(());
+        }
+
+        current = object.entry(field).or_insert_with(|| Value::Object(Map::new()));
+    }
+
+    Ok(())
+}
+
+fn error_response(status: &tonic::Status) -> Response {
+    let body = status
+        .metadata()
+        .get(CUSTOM_ERROR)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| serde_json::from_str::<Value>(value).ok())
+        .unwrap_or_else(|| json!({
+            "message": status.message(),
+            "type": format!("{:?}", status.code()),
+        }));
+
+    let mut headers = HeaderMap::new();
+    if let Some(id) = status.metadata().get(REQUEST_ID_HEADER).and_then(|id| HeaderValue::from_bytes(id.as_bytes()).ok()) {
+        headers.insert(REQUEST_ID_HEADER, id);
+    }
+
+    (http_status(status.code()), headers, Json(body)).into_response()
+}
+
+/// HTTP equivalent of a gRPC status code, as used by grpc-gateway.
+pub fn http_status(code: tonic::Code) -> StatusCode {
+    match code {
+        tonic::Code::Ok => StatusCode::OK,
+        tonic::Code::Cancelled => StatusCode::from_u16(499).unwrap(),
+        tonic::Code::InvalidArgument => StatusCode::BAD_REQUEST,
+        tonic::Code::FailedPrecondition => StatusCode::BAD_REQUEST,
+        tonic::Code::OutOfRange => StatusCode::BAD_REQUEST,
+        tonic::Code::Unauthenticated => StatusCode::UNAUTHORIZED,
+        tonic::Code::PermissionDenied => StatusCode::FORBIDDEN,
+        tonic::Code::NotFound => StatusCode::NOT_FOUND,
+        tonic::Code::AlreadyExists => StatusCode::CONFLICT,
+        tonic::Code::Aborted => StatusCode::CONFLICT,
+        tonic::Code::ResourceExhausted => StatusCode::TOO_MANY_REQUESTS,
+        tonic::Code::Unimplemented => StatusCode::NOT_IMPLEMENTED,
+        tonic::Code::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+        tonic::Code::DeadlineExceeded => StatusCode::GATEWAY_TIMEOUT,
+        tonic::Code::Unknown | tonic::Code::Internal | tonic::Code::DataLoss => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+fn method_filter(method: &str) -> Option<MethodFilter> {
+    match method {
+        "GET" => Some(MethodFilter::GET),
+        "PUT" => Some(MethodFilter::PUT),
+        "POST" => Some(MethodFilter::POST),
+        "DELETE" => Some(MethodFilter::DELETE),
+        "PATCH" => Some(MethodFilter::PATCH),
+        _ => None,
+    }
+}
+
+/// Turns `/v1/items/{name}` into axum's `/v1/items/:name`. Multi-segment
+/// captures (`{name=**}`) become a trailing wildcard.
+fn axum_path(template: &str) -> String {
+    template
+        .split('/')
+        .map(|segment| match segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+            Some(variable) => match variable.split_once('=') {
+                Some((field, pattern)) if pattern.contains("**") => format!("*{}", field),
+                Some((field, _)) => format!(":{}", field),
+                None => format!(":{}", variable),
+            },
+            None => segment.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn forwarded_request_header(name: &HeaderName) -> bool {
+    !matches!(
+        name.as_str(),
+        "host" | "connection" | "content-type" | "content-length" | "transfer-encoding" | "te" | "accept-encoding"
+    )
+}
+
+/// Keeps response metadata such as `x-cache` and `cache-control`, drops the gRPC framing headers.
+fn response_headers(headers: &http::HeaderMap) -> HeaderMap {
+    let mut forwarded = HeaderMap::new();
+    for (name, value) in headers {
+        if name.as_str() == "content-type" || name.as_str().starts_with("grpc-") {
+            continue;
+        }
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_str().as_bytes()),
+            HeaderValue::from_bytes(value.as_bytes()),
+        ) {
+            forwarded.append(name, value);
+        }
+    }
+    forwarded
+}
@@ -0,0 +1,141 @@
+use prost::Message;
+
+// Only the parts of `google/protobuf/descriptor.proto` needed to reach the
+// `google.api.http` method option; prost skips every other field.
+
+#[derive(Clone, PartialEq, Message)]
+struct FileDescriptorSet {
+    #[prost(message, repeated, tag = "1")]
+    file: Vec<FileDescriptorProto>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct FileDescriptorProto {
+    #[prost(string, optional, tag = "2")]
+    package: Option<String>,
+    #[prost(message, repeated, tag = "6")]
+    service: Vec<ServiceDescriptorProto>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ServiceDescriptorProto {
+    #[prost(string, optional, tag = "1")]
+    name: Option<String>,
+    #[prost(message, repeated, tag = "2")]
+    method: Vec<MethodDescriptorProto>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct MethodDescriptorProto {
+    #[prost(string, optional, tag = "1")]
+    name: Option<String>,
+    #[prost(message, optional, tag = "4")]
+    options: Option<MethodOptions>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct MethodOptions {
+    /// `google.api.http` extension.
+    #[prost(message, optional, tag = "72295728")]
+    http: Option<HttpRule>,
+}
+
+/// `google.api.HttpRule`
+#[derive(Clone, PartialEq, Message)]
+struct HttpRule {
+    #[prost(oneof = "Pattern", tags = "2, 3, 4, 5, 6, 8")]
+    pattern: Option<Pattern>,
+    #[prost(string, tag = "7")]
+    body: String,
+    #[prost(message, repeated, tag = "11")]
+    additional_bindings: Vec<HttpRule>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+enum Pattern {
+    #[prost(string, tag = "2")]
+    Get(String),
+    #[prost(string, tag = "3")]
+    Put(String),
+    #[prost(string, tag = "4")]
+    Post(String),
+    #[prost(string, tag = "5")]
+    Delete(String),
+    #[prost(string, tag = "6")]
+    Patch(String),
+    #[prost(message, tag = "8")]
+    Custom(CustomHttpPattern),
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct CustomHttpPattern {
+    #[prost(string, tag = "1")]
+    kind: String,
+    #[prost(string, tag = "2")]
+    path: String,
+}
+
+/// A REST route declared on an RPC with the `google.api.http` option.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpBinding {
+    /// Fully-qualified gRPC path, e.g. `/echo.v1.EchoService/UnaryEcho`.
+    pub grpc_method: String,
+    pub http_method: String,
+    /// Path template, e.g. `/v1/echo/{message}`.
+    pub path: String,
+    /// `*` maps the whole body onto the request, a field name maps it onto that
+    /// field, `None` means the request is built from path and query parameters.
+    pub body: Option<String>,
+}
+
+/// Reads every `google.api.http` binding, including `additional_bindings`,
+/// from an encoded `FileDescriptorSet`.
+pub fn http_bindings(descriptor_set: &[u8]) -> Result<Vec<HttpBinding>, prost::DecodeError> {
+    let descriptor_set = FileDescriptorSet::decode(descriptor_set)?;
+    let mut bindings = Vec::new();
+
+    for file in descriptor_set.file {
+        let package = file.package.unwrap_or_default();
+        for service in file.service {
+            let service_name = match package.is_empty() {
+                true => service.name.unwrap_or_default(),
+                false => format!("{}.{}", package, service.name.unwrap_or_default()),
+            };
+
+            for method in service.method {
+                let rule = match method.options.and_then(|options| options.http) {
+                    Some(rule) => rule,
+                    None => continue,
+                };
+                let grpc_method = format!("/{}/{}", service_name, method.name.unwrap_or_default());
+
+                let additional_bindings = rule.additional_bindings.clone();
+                for rule in std::iter::once(rule).chain(additional_bindings) {
+                    if let Some(binding) = to_binding(&grpc_method, rule) {
+                        bindings.push(binding);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(bindings)
+}
+
+fn to_binding(grpc_method: &str, rule: HttpRule) -> Option<HttpBinding> {
+    let (http_method, path) = match rule.pattern? {
+        Pattern::Get(path) => ("GET".to_string(), path),
+        Pattern::Put(path) => ("PUT".to_string(), path),
+        Pattern::Post(path) => ("POST".to_string(), path),
+        Pattern::Delete(path) => ("DELETE".to_string(), path),
+        Pattern::Patch(path) => ("PATCH".to_string(), path),
+        Pattern::Custom(custom) => (custom.kind.to_uppercase(), custom.path),
+    };
+
+    Some(HttpBinding {
+        grpc_method: grpc_method.to_string(),
+        http_method,
+        path,
+        body: Some(rule.body).filter(|body| !body.is_empty()),
+    })
+}
@@ -0,0 +1,5 @@
+mod gateway;
+mod http_rule;
+
+pub use gateway::*;
+pub use http_rule::*;
@@ -1,37 +1,87 @@
-use std::env;
+#![allow(clippy::module_inception)]
+
 use std::net::{Ipv6Addr, SocketAddr, SocketAddrV6};
-use ::log::{error, info};
+use tracing::{error, info};
+use opentelemetry::trace::TraceError;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, reload, EnvFilter, Layer, Registry};
+use crate::config::{LogFormat, LoggingOptions, OtelOptions};
+use crate::telemetry::otel_layer;
 
+pub mod cli;
+pub mod config;
+pub mod gateway;
+pub mod metrics;
 pub mod server;
 pub mod database;
 pub mod errors;
+pub mod telemetry;
 pub mod utils;
 
-pub fn init_service_logging() {
-    env_logger::builder()
-        .target(env_logger::Target::Stdout)
-        .filter_level(log::LevelFilter::Info)
-        .parse_env("RUST_LOG")
+/// Logs to stdout in the format of `logging`, filtered by `RUST_LOG` (`info`
+/// by default).
+/// Records of crates still using `log` are forwarded. With `otel`, `info`
+/// spans are exported too whatever `RUST_LOG` says, see [`otel_layer`].
+/// The returned [`LogFilter`] changes the stdout filter at runtime.
+pub fn init_service_logging(logging: &LoggingOptions, otel: Option<&OtelOptions>) -> Result<LogFilter, TraceError> {
+    let (filter, handle) = LogFilter::new(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")));
+    let stdout: Box<dyn Layer<Registry> + Send + Sync> = match logging.format {
+        LogFormat::Json => Box::new(fmt::layer().json().flatten_event(true).with_current_span(false).with_span_list(true)),
+        LogFormat::Text => Box::new(fmt::layer()),
+    };
+    let otel = match otel {
+        Some(options) => otel_layer(options)?,
+        None => None,
+    };
+
+    tracing_subscriber::registry()
+        .with(stdout.with_filter(filter))
+        .with(otel.map(|layer| layer.with_filter(LevelFilter::INFO)))
         .init();
+
+    Ok(handle)
+}
+
+/// Handle on the `RUST_LOG` filter of the stdout logs.
+#[derive(Clone)]
+pub struct LogFilter {
+    handle: reload::Handle<EnvFilter, Registry>,
+}
+
+impl LogFilter {
+    /// Wraps `filter` in a layer that the returned handle can swap.
+    pub fn new(filter: EnvFilter) -> (reload::Layer<EnvFilter, Registry>, LogFilter) {
+        let (layer, handle) = reload::Layer::new(filter);
+        (layer, LogFilter { handle })
+    }
+
+    pub fn current(&self) -> Option<String> {
+        self.handle.with_current(|filter| filter.to_string()).ok()
+    }
+
+    /// Replaces the filter with `directives`, in the `RUST_LOG` syntax.
+    pub fn set(&self, directives: &str) -> Result<(), String> {
+        let filter = EnvFilter::try_new(directives).map_err(|e| e.to_string())?;
+        self.handle.reload(filter).map_err(|e| e.to_string())
+    }
 }
 
-pub fn report_error<E: 'static>(err: &E)
+pub fn report_error<E>(err: &E)
 where
-    E: std::error::Error,
+    E: std::error::Error + ?Sized + 'static,
     E: Send + Sync,
 {
-    let mut stack = String::from("\n");
-    if let Some(cause) = err.source() {
-        for (i, e) in std::iter::successors(Some(cause), |e| e.source()).enumerate() {
-            stack.push_str(&format!("   {}: {}\n", i, e));
-        }
+    let causes: Vec<String> = std::iter::successors(err.source(), |e| e.source()).map(|e| e.to_string()).collect();
+    if causes.is_empty() {
+        error!(error = %err, "{}", err);
+    } else {
+        error!(error = %err, caused_by = ?causes, "{}", err);
     }
-    error!("[ERROR] {}\nCaused by: {}", err, stack);
 }
 
-pub fn create_socket_addr(port: u16) -> SocketAddr {
-    let is_ipv6 = env::var("ENABLE_IPV6").unwrap_or_default().parse::<bool>().unwrap_or(false);
-
+pub fn create_socket_addr(port: u16, is_ipv6: bool) -> SocketAddr {
     if is_ipv6 {
         info!("Using IPv6");
         SocketAddr::from(SocketAddrV6::new(
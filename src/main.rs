@@ -1,64 +1,155 @@
-use std::env;
 use std::sync::Arc;
+use std::time::Duration;
 
-use axum::{routing::get, Router};
-use autometrics::prometheus_exporter;
+use clap::Parser;
 use dotenvy::dotenv;
-use rust_server::{create_socket_addr, database, init_service_logging};
+use tonic_health::pb::health_check_response::ServingStatus;
+use rust_server::{create_socket_addr, database, init_service_logging, LogFilter};
+use rust_server::cli::{self, Cli, Command, HealthcheckTarget, ServeArgs};
+use rust_server::config::Config;
 use rust_server::database::CacheClient;
-use rust_server::server::start_server;
+use rust_server::metrics::init_metrics;
+use rust_server::telemetry::shutdown_tracing;
+use rust_server::server::{admin_router, shutdown_signal, start_rest_gateway, start_server, Admin, AppBuilder, AppState};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv().ok();
-    init_service_logging();
-    prometheus_exporter::init();
+    let cli = Cli::parse();
+    let default_command = Command::default();
+    let command = cli.command.as_ref().unwrap_or(&default_command);
 
-    // Set up the database connection
-    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    // Logging follows the config when it's valid. Its errors are logged once
+    // logging is set up, by the commands that need it. Only the server
+    // exports traces.
+    let config = cli.try_load_config();
+    let logging = config.as_ref().map(|config| config.logging.clone()).unwrap_or_default();
+    let otel = match command {
+        Command::Serve(_) => config.as_ref().ok().map(|config| &config.otel),
+        _ => None,
+    };
+    let log_filter = init_service_logging(&logging, otel)?;
 
-    database::check_for_migrations(&database_url.clone())
-        .await
-        .expect("An error occurred while running migrations");
+    match command {
+        Command::Serve(args) => serve(Cli::config_or_exit(config), args, log_filter).await,
+        Command::Migrate { action } => {
+            let config = Cli::config_or_exit(config);
+            cli::migrate(&config.database.url, *action).await?;
+            Ok(())
+        }
+        Command::ApiKeys { action } => cli::api_keys(&Cli::config_or_exit(config).database, action).await,
+        Command::CheckConfig => {
+            print!("{}", Cli::config_or_exit(config).redacted());
+            Ok(())
+        }
+        Command::PrintDescriptors(args) => cli::print_descriptors(&AppBuilder::new(), args),
+        Command::GenerateSlo(args) => cli::generate_slo(&Cli::config_or_exit(config), &AppBuilder::new(), args),
+        Command::Healthcheck(args) => {
+            let target = match (&args.addr, args.admin) {
+                (Some(addr), true) => HealthcheckTarget::parse_admin(addr),
+                (Some(addr), false) => HealthcheckTarget::parse(addr),
+                (None, true) => HealthcheckTarget::admin_from_config(&Cli::config_or_exit(config)),
+                (None, false) => HealthcheckTarget::from_config(&Cli::config_or_exit(config))?,
+            };
+
+            match cli::healthcheck(&target, args).await {
+                Ok(ServingStatus::Serving) => Ok(()),
+                Ok(status) => {
+                    tracing::error!("Server is {}", status.as_str_name());
+                    std::process::exit(1);
+                }
+                Err(e) => {
+                    tracing::error!("Health check failed: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+}
+
+async fn serve(config: Config, args: &ServeArgs, log_filter: LogFilter) -> Result<(), Box<dyn std::error::Error>> {
+    init_metrics();
 
-    let pool = database::connect(&database_url)
+    // Set up the database connection
+    if !args.skip_migrations {
+        database::check_for_migrations(&config.database.url)
+            .await
+            .expect("An error occurred while running migrations");
+    }
+
+    let pool = database::connect(&config.database)
         .await
         .expect("Couldn't connect to the database");
 
     // Set up the Redis connection
-    let uri_scheme = match env::var("REDIS_TLS").unwrap_or_default().parse::<bool>() {
-        Ok(bool) => if bool { "rediss" } else { "redis" },
-        Err(_) => "redis",
-    };
-
-    let redis_host = env::var("REDIS_HOSTNAME").expect("REDIS_HOSTNAME must be set");
-    let redis_pass = env::var("REDIS_PASSWORD").unwrap_or_default();
-    let redis_conn_url = format!("{}://:{}@{}", uri_scheme, redis_pass, redis_host);
-    let r_client = database::connect_redis(redis_conn_url)
+    let r_client = database::connect_redis(config.redis.url())
         .expect("Couldn't connect to Redis");
 
     // Start the gRPC server
-    let port = env::var("PORT").unwrap_or_else(|_| "50051".to_string()).parse().expect("PORT must be a number");
-    let cache_ttl = env::var("CACHE_TTL").unwrap_or_else(|_| "60".to_string()).parse::<u64>().expect("CACHE_TTL must be a number");
+    let cache_client = CacheClient::new(r_client, config.redis.cache_ttl_secs);
 
-    let cache_client = CacheClient::new(r_client, cache_ttl);
+    let pool = Arc::new(pool);
+    let app = AppBuilder::new();
+    let state = AppState { pool: pool.clone(), cache: cache_client };
+    let server = start_server(&config, &app, state.clone()).await?;
 
-    let _server = start_server(
-        Arc::new(pool),
-        cache_client,
-        port
-    );
+    let rest_gateway = match config.rest.port {
+        Some(rest_port) => {
+            let rest_addr = create_socket_addr(rest_port, config.server.enable_ipv6);
+            Some(start_rest_gateway(app.rest_gateway()?, server.routes.clone(), server.middleware.clone(), rest_addr, server.shutdown.clone()).await?)
+        }
+        None => None,
+    };
 
-    let app = Router::new().route(
-        "/metrics",
-        get(|| async { prometheus_exporter::encode_http_response() }),
-    );
+    let app = admin_router(Admin {
+        state,
+        shutdown: server.shutdown.clone(),
+        config: config.redacted(),
+        log_filter: Some(log_filter),
+        token: config.metrics.admin_token.clone(),
+        timeout: Duration::from_millis(config.metrics.readyz_timeout_ms),
+    });
 
-    let metrics_port: u16 = env::var("METRICS_PORT").unwrap_or_else(|_| "3000".to_string()).parse().expect("METRICS_PORT must be a number");
-    let metrics_addr = create_socket_addr(metrics_port);
+    let metrics_port = config.metrics.port;
+    let metrics_addr = create_socket_addr(metrics_port, config.server.enable_ipv6);
     let listener = tokio::net::TcpListener::bind(metrics_addr).await.unwrap();
-    log::info!("Metrics server listening on port {}", metrics_port);
-    axum::serve(listener, app).await.unwrap();
+    tracing::info!("Metrics and admin server listening on port {}", metrics_port);
+
+    let metrics_shutdown = server.shutdown.clone();
+    let metrics_server = tokio::spawn(async move {
+        axum::serve(listener, app)
+            .with_graceful_shutdown(async move { metrics_shutdown.triggered().await })
+            .await
+            .unwrap();
+    });
+
+    // Wait for SIGINT/SIGTERM, then drain every server before closing the pool
+    shutdown_signal().await;
+
+    let grace_period = config.server.shutdown_grace_period_secs;
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(grace_period);
+
+    tracing::info!("Shutting down, draining in-flight requests for up to {}s...", grace_period);
+    server.shutdown();
+
+    let drained = tokio::time::timeout_at(deadline, async {
+        let _ = server.handle.await;
+        let _ = metrics_server.await;
+        if let Some(rest_gateway) = rest_gateway {
+            let _ = rest_gateway.await;
+        }
+    }).await;
+
+    if drained.is_err() {
+        tracing::warn!("Grace period elapsed before all requests completed");
+    }
+
+    if tokio::time::timeout_at(deadline, pool.close()).await.is_err() {
+        tracing::warn!("Database pool did not close before the grace period elapsed");
+    }
+
+    tracing::info!("Shutdown complete");
+    shutdown_tracing().await;
 
     Ok(())
-}
\ No newline at end of file
+}
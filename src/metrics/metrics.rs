@@ -0,0 +1,225 @@
+use std::sync::OnceLock;
+
+use autometrics::settings::AutometricsSettings;
+use prometheus_client::encoding::EncodeLabelSet;
+use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
+use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::{exponential_buckets, Histogram};
+use prometheus_client::registry::{Registry, Unit};
+
+static METRICS: OnceLock<Metrics> = OnceLock::new();
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct ReloadLabels {
+    /// `success` or `failure`
+    pub result: &'static str,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct StreamLabels {
+    /// gRPC method name, e.g. `ServerStreamingEcho`
+    pub method: &'static str,
+    /// `received` or `sent`
+    pub direction: &'static str,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct MethodLabels {
+    /// gRPC path, e.g. `/echo.v1.EchoService/UnaryEcho`
+    pub method: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct RateLimitLabels {
+    pub method: String,
+    /// `allowed`, `limited` or `error` when Redis couldn't be reached
+    pub decision: &'static str,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct PolicyLabels {
+    /// Method of the matching rule, `default` when none matched
+    pub rule: String,
+    /// `allowed`, `denied` or `dry_run_denied`
+    pub decision: &'static str,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct CacheLabels {
+    /// Cache key prefix, e.g. `unary_echo`
+    pub method: String,
+    /// `hit`, `miss` or `error` when the cache couldn't be read
+    pub result: &'static str,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct RedisLabels {
+    /// Redis command, e.g. `GET`, or what a pipeline does, e.g. `rate_limit_hit`
+    pub command: &'static str,
+    /// `success` or `failure`
+    pub result: &'static str,
+}
+
+type HistogramFamily<L> = Family<L, Histogram, fn() -> Histogram>;
+
+/// From 0.5ms to about 4s.
+fn latency_histogram() -> Histogram {
+    Histogram::new(exponential_buckets(0.0005, 2.0, 14))
+}
+
+/// Metrics recorded outside of `#[autometrics]` functions. They are exported
+/// on `/metrics` next to the autometrics ones.
+pub struct Metrics {
+    pub tls_reloads: Family<ReloadLabels, Counter>,
+    pub streamed_messages: Family<StreamLabels, Counter>,
+    pub requests_shed: Family<MethodLabels, Counter>,
+    pub concurrency_limit: Family<MethodLabels, Gauge>,
+    pub rate_limit_decisions: Family<RateLimitLabels, Counter>,
+    pub policy_decisions: Family<PolicyLabels, Counter>,
+    pub panics: Family<MethodLabels, Counter>,
+    pub cache_lookups: Family<CacheLabels, Counter>,
+    pub redis_command_duration: HistogramFamily<RedisLabels>,
+    pub db_pool_connections: Gauge,
+    pub db_pool_idle_connections: Gauge,
+    pub db_pool_pending_acquires: Gauge,
+    pub db_pool_acquire_duration: Histogram,
+    pub slo_requests: Family<MethodLabels, Counter>,
+    pub slo_errors: Family<MethodLabels, Counter>,
+    pub slo_slow_requests: Family<MethodLabels, Counter>,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Metrics {
+            tls_reloads: Family::default(),
+            streamed_messages: Family::default(),
+            requests_shed: Family::default(),
+            concurrency_limit: Family::default(),
+            rate_limit_decisions: Family::default(),
+            policy_decisions: Family::default(),
+            panics: Family::default(),
+            cache_lookups: Family::default(),
+            redis_command_duration: Family::new_with_constructor(latency_histogram),
+            db_pool_connections: Gauge::default(),
+            db_pool_idle_connections: Gauge::default(),
+            db_pool_pending_acquires: Gauge::default(),
+            db_pool_acquire_duration: latency_histogram(),
+            slo_requests: Family::default(),
+            slo_errors: Family::default(),
+            slo_slow_requests: Family::default(),
+        }
+    }
+}
+
+impl Metrics {
+    fn register(&self, registry: &mut Registry) {
+        registry.register(
+            "tls_reloads",
+            "TLS certificate reloads by result",
+            self.tls_reloads.clone(),
+        );
+        registry.register(
+            "streamed_messages",
+            "Messages received and sent on streaming RPCs",
+            self.streamed_messages.clone(),
+        );
+        registry.register(
+            "requests_shed",
+            "Requests rejected because their method was at its concurrency limit",
+            self.requests_shed.clone(),
+        );
+        registry.register(
+            "concurrency_limit",
+            "Current concurrency limit per method",
+            self.concurrency_limit.clone(),
+        );
+        registry.register(
+            "rate_limit_decisions",
+            "Requests checked against a rate limit, by decision",
+            self.rate_limit_decisions.clone(),
+        );
+        registry.register(
+            "policy_decisions",
+            "Requests checked against the authorization policy, by decision",
+            self.policy_decisions.clone(),
+        );
+        registry.register(
+            "panics",
+            "Handler panics turned into internal errors",
+            self.panics.clone(),
+        );
+        registry.register(
+            "cache_lookups",
+            "Cached RPCs by cache result",
+            self.cache_lookups.clone(),
+        );
+        registry.register_with_unit(
+            "redis_command_duration",
+            "Time taken by Redis commands, connecting included",
+            Unit::Seconds,
+            self.redis_command_duration.clone(),
+        );
+        registry.register(
+            "db_pool_connections",
+            "Connections open in the database pool",
+            self.db_pool_connections.clone(),
+        );
+        registry.register(
+            "db_pool_idle_connections",
+            "Idle connections in the database pool",
+            self.db_pool_idle_connections.clone(),
+        );
+        registry.register(
+            "db_pool_pending_acquires",
+            "Requests waiting for a database connection",
+            self.db_pool_pending_acquires.clone(),
+        );
+        registry.register_with_unit(
+            "db_pool_acquire_duration",
+            "Time spent waiting for a database connection",
+            Unit::Seconds,
+            self.db_pool_acquire_duration.clone(),
+        );
+        registry.register(
+            "slo_requests",
+            "Requests to methods with an objective, cancelled ones excepted",
+            self.slo_requests.clone(),
+        );
+        registry.register(
+            "slo_errors",
+            "Requests that spent the error budget of their method",
+            self.slo_errors.clone(),
+        );
+        registry.register(
+            "slo_slow_requests",
+            "Requests slower than the latency objective of their method",
+            self.slo_slow_requests.clone(),
+        );
+    }
+}
+
+impl Metrics {
+    pub fn stream_message(&self, method: &'static str, direction: &'static str) {
+        self.streamed_messages.get_or_create(&StreamLabels { method, direction }).inc();
+    }
+
+    pub fn cache_lookup(&self, method: &str, result: &'static str) {
+        self.cache_lookups.get_or_create(&CacheLabels { method: method.to_string(), result }).inc();
+    }
+}
+
+pub fn metrics() -> &'static Metrics {
+    METRICS.get_or_init(Metrics::default)
+}
+
+/// Registers the custom metrics with the autometrics registry and starts the
+/// Prometheus exporter. Must be called once, before any instrumented code runs.
+pub fn init_metrics() {
+    let mut registry = Registry::default();
+    metrics().register(&mut registry);
+
+    AutometricsSettings::builder()
+        .prometheus_client_registry(registry)
+        .init();
+}
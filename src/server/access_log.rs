@@ -0,0 +1,237 @@
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use futures_util::future::{BoxFuture, FutureExt};
+use futures_util::StreamExt;
+use serde_json::Value;
+use tonic::body::BoxBody;
+use tonic::codegen::{http, Body, Bytes};
+use tower::{Layer, Service};
+use tracing::field;
+use tracing::Span;
+use crate::config::AccessLogOptions;
+use crate::server::{peer_addr, ClientIdentity};
+
+const GRPC_STATUS: &str = "grpc-status";
+const CUSTOM_ERROR: &str = "x-tonic-error";
+const CACHE_STATUS: &str = "x-cache";
+
+/// Decides which completed RPCs get an access log line. `access_log.enabled`
+/// is on by default, the `Default` value here logs nothing.
+#[derive(Debug, Clone, Default)]
+pub struct AccessLog {
+    enabled: bool,
+    sample_ratio: f64,
+    slow: Option<Duration>,
+}
+
+impl AccessLog {
+    pub fn new(options: &AccessLogOptions) -> Self {
+        AccessLog {
+            enabled: options.enabled,
+            sample_ratio: options.sample_ratio,
+            slow: (options.slow_ms > 0).then(|| Duration::from_millis(options.slow_ms)),
+        }
+    }
+
+    fn is_slow(&self, latency: Duration) -> bool {
+        self.slow.is_some_and(|threshold| latency >= threshold)
+    }
+
+    /// Failed and slow RPCs are always logged, the others are sampled.
+    fn should_log(&self, status: tonic::Code, slow: bool) -> bool {
+        status != tonic::Code::Ok || slow || rand::random::<f64>() < self.sample_ratio
+    }
+}
+
+/// Logs one `access_log` event per completed RPC with its method, status,
+/// error type, latency, peer, client certificate subject, payload sizes and
+/// cache status. RPCs slower than the threshold are logged as warnings.
+/// Goes below the [`RpcSpanLayer`](crate::server::RpcSpanLayer) so events carry the RPC span.
+#[derive(Debug, Clone)]
+pub struct AccessLogLayer {
+    log: AccessLog,
+}
+
+impl AccessLogLayer {
+    pub fn new(log: AccessLog) -> Self {
+        AccessLogLayer { log }
+    }
+}
+
+impl<S> Layer<S> for AccessLogLayer {
+    type Service = AccessLogService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        AccessLogService { inner, log: self.log.clone() }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AccessLogService<S> {
+    inner: S,
+    log: AccessLog,
+}
+
+impl<S> Service<http::Request<tonic::transport::Body>> for AccessLogService<S>
+where
+    S: Service<http::Request<tonic::transport::Body>, Response = http::Response<BoxBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<tonic::transport::Body>) -> Self::Future {
+        if !self.log.enabled {
+            return self.inner.call(request).boxed();
+        }
+
+        let received = Arc::new(AtomicU64::new(0));
+        let mut entry = Entry {
+            log: self.log.clone(),
+            span: Span::current(),
+            started: Instant::now(),
+            finished: None,
+            method: request.uri().path().to_string(),
+            peer: peer_addr(request.extensions()),
+            identity: ClientIdentity::from_extensions(request.extensions()).map(|identity| identity.subject),
+            received: received.clone(),
+            sent: 0,
+            status: None,
+            error: None,
+            cache: None,
+        };
+
+        // Client streams have no trailers, so the body can be rebuilt from its chunks
+        let request = request.map(|body| {
+            tonic::transport::Body::wrap_stream(body.inspect(move |chunk| {
+                if let Ok(chunk) = chunk {
+                    received.fetch_add(chunk.len() as u64, Ordering::Relaxed);
+                }
+            }))
+        });
+        let future = self.inner.call(request);
+        async move {
+            let response = future.await?;
+            entry.inspect(response.headers());
+            Ok(response.map(|inner| BoxBody::new(LoggedBody { inner, entry })))
+        }
+        .boxed()
+    }
+}
+
+/// What is known about an RPC so far, logged once its response body is dropped.
+struct Entry {
+    log: AccessLog,
+    span: Span,
+    started: Instant,
+    finished: Option<Instant>,
+    method: String,
+    peer: Option<SocketAddr>,
+    identity: Option<String>,
+    received: Arc<AtomicU64>,
+    sent: u64,
+    status: Option<tonic::Code>,
+    error: Option<String>,
+    cache: Option<String>,
+}
+
+impl Entry {
+    /// Picks the status, error type and cache status from response headers or trailers.
+    fn inspect(&mut self, headers: &http::HeaderMap) {
+        if let Some(status) = headers.get(GRPC_STATUS) {
+            self.status = Some(tonic::Code::from_bytes(status.as_bytes()));
+            self.finished = Some(Instant::now());
+        }
+        if let Some(error) = headers.get(CUSTOM_ERROR).and_then(|value| value.to_str().ok()) {
+            self.error = serde_json::from_str::<Value>(error)
+                .ok()
+                .and_then(|error| error["type"].as_str().map(str::to_string));
+        }
+        if let Some(cache) = headers.get(CACHE_STATUS).and_then(|value| value.to_str().ok()) {
+            self.cache = Some(cache.to_string());
+        }
+    }
+}
+
+impl Drop for Entry {
+    fn drop(&mut self) {
+        // A response dropped before its status was sent was abandoned by the client
+        let status = self.status.unwrap_or(tonic::Code::Cancelled);
+        let latency = self.finished.unwrap_or_else(Instant::now) - self.started;
+        let slow = self.log.is_slow(latency);
+        if !self.log.should_log(status, slow) {
+            return;
+        }
+
+        let _entered = self.span.enter();
+        macro_rules! access_log {
+            ($level:ident, $message:literal) => {
+                tracing::$level!(
+                    target: "access_log",
+                    method = %self.method,
+                    status = ?status,
+                    error = self.error.as_deref(),
+                    latency_ms = latency.as_secs_f64() * 1000.0,
+                    peer = self.peer.map(field::display),
+                    identity = self.identity.as_deref(),
+                    request_bytes = self.received.load(Ordering::Relaxed),
+                    response_bytes = self.sent,
+                    cache = self.cache.as_deref(),
+                    $message
+                )
+            };
+        }
+        match slow {
+            true => access_log!(warn, "Slow RPC"),
+            false => access_log!(info, "RPC completed"),
+        }
+    }
+}
+
+/// Response body that counts the bytes sent and records the status from its trailers.
+struct LoggedBody {
+    inner: BoxBody,
+    entry: Entry,
+}
+
+impl Body for LoggedBody {
+    type Data = Bytes;
+    type Error = tonic::Status;
+
+    fn poll_data(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        let this = &mut *self;
+        let poll = Pin::new(&mut this.inner).poll_data(cx);
+        if let Poll::Ready(Some(Ok(data))) = &poll {
+            this.entry.sent += data.len() as u64;
+        }
+        poll
+    }
+
+    fn poll_trailers(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<Option<http::HeaderMap>, Self::Error>> {
+        let this = &mut *self;
+        let poll = Pin::new(&mut this.inner).poll_trailers(cx);
+        match &poll {
+            Poll::Ready(Ok(Some(trailers))) => this.entry.inspect(trailers),
+            Poll::Ready(Ok(None)) if this.entry.status.is_none() => {
+                this.entry.status = Some(tonic::Code::Ok);
+                this.entry.finished = Some(Instant::now());
+            }
+            _ => {}
+        }
+        poll
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+}
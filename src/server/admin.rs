@@ -0,0 +1,143 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use autometrics::prometheus_exporter;
+use axum::extract::{Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use tracing::{info, warn};
+use crate::server::{AppState, DependencyStatus, ShutdownTrigger};
+use crate::LogFilter;
+
+/// What the admin endpoints of the metrics port report on and control.
+#[derive(Clone)]
+pub struct Admin {
+    pub state: AppState,
+    pub shutdown: ShutdownTrigger,
+    /// Served on `/config`, see [`Config::redacted`](crate::config::Config::redacted).
+    pub config: String,
+    /// `None` when logging was set up without a reloadable filter.
+    pub log_filter: Option<LogFilter>,
+    /// Bearer token required by every endpoint but the probes when set.
+    /// `/config` and `/loglevel` are refused without one.
+    pub token: Option<String>,
+    /// How long `/readyz` waits for Postgres and Redis.
+    pub timeout: Duration,
+}
+
+/// Serves `/metrics` and the admin endpoints:
+/// - `/livez` answers as long as the process does
+/// - `/readyz` fails while Postgres or Redis is unreachable, or the server drains
+/// - `/buildinfo` gives the name and version of the server
+/// - `/config` dumps the configuration with its secrets redacted
+/// - `GET` and `PUT /loglevel` read and replace the `RUST_LOG` filter
+///
+/// The probes never ask for the token, so orchestrators can reach them.
+pub fn admin_router(admin: Admin) -> Router {
+    let token = admin.token.clone().map(Arc::new);
+    let probes = Router::new()
+        .route("/livez", get(|| async { "ok" }))
+        .route("/readyz", get(readyz));
+    let info = Router::new()
+        .route("/metrics", get(|| async { prometheus_exporter::encode_http_response() }))
+        .route("/buildinfo", get(buildinfo))
+        .layer(middleware::from_fn_with_state(token.clone(), require_token));
+    let control = Router::new()
+        .route("/config", get(config))
+        .route("/loglevel", get(log_level).put(set_log_level))
+        .layer(middleware::from_fn_with_state(token, require_configured_token));
+
+    probes.merge(info).merge(control).with_state(Arc::new(admin))
+}
+
+/// Lets everything through unless a token is configured.
+async fn require_token(State(token): State<Option<Arc<String>>>, request: Request, next: Next) -> Response {
+    match token {
+        Some(token) if !is_authorized(&request, &token) => unauthorized(),
+        _ => next.run(request).await,
+    }
+}
+
+/// Refuses everything unless a token is configured, for the endpoints that
+/// leak the configuration or change the server.
+async fn require_configured_token(State(token): State<Option<Arc<String>>>, request: Request, next: Next) -> Response {
+    match token {
+        None => (StatusCode::FORBIDDEN, "Set metrics.admin_token (ADMIN_TOKEN) to use this endpoint").into_response(),
+        Some(token) if !is_authorized(&request, &token) => unauthorized(),
+        Some(_) => next.run(request).await,
+    }
+}
+
+fn is_authorized(request: &Request, token: &str) -> bool {
+    let bearer = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    // Comparing digests keeps the comparison time independent of the token
+    bearer.is_some_and(|bearer| Sha256::digest(bearer) == Sha256::digest(token))
+}
+
+fn unauthorized() -> Response {
+    (StatusCode::UNAUTHORIZED, [(header::WWW_AUTHENTICATE, "Bearer")]).into_response()
+}
+
+async fn readyz(State(admin): State<Arc<Admin>>) -> Response {
+    if admin.shutdown.is_triggered() {
+        return (StatusCode::SERVICE_UNAVAILABLE, Json(json!({ "status": "draining" }))).into_response();
+    }
+
+    let status = DependencyStatus::check(&admin.state.pool, &admin.state.cache, admin.timeout).await;
+    let code = match status.is_ok() {
+        true => StatusCode::OK,
+        false => StatusCode::SERVICE_UNAVAILABLE,
+    };
+    let body = json!({
+        "status": if status.is_ok() { "ready" } else { "unavailable" },
+        "postgres": status.postgres.as_deref().unwrap_or("ok"),
+        "redis": status.redis.as_deref().unwrap_or("ok"),
+    });
+    (code, Json(body)).into_response()
+}
+
+async fn buildinfo() -> Json<serde_json::Value> {
+    Json(json!({
+        "name": env!("CARGO_PKG_NAME"),
+        "version": env!("CARGO_PKG_VERSION"),
+        "profile": if cfg!(debug_assertions) { "debug" } else { "release" },
+    }))
+}
+
+async fn config(State(admin): State<Arc<Admin>>) -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, "application/toml")], admin.config.clone())
+}
+
+async fn log_level(State(admin): State<Arc<Admin>>) -> Response {
+    match admin.log_filter.as_ref().and_then(LogFilter::current) {
+        Some(filter) => filter.into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// Takes the new filter as the request body, e.g. `info,rust_server=debug`.
+async fn set_log_level(State(admin): State<Arc<Admin>>, directives: String) -> Response {
+    let Some(log_filter) = &admin.log_filter else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    match log_filter.set(directives.trim()) {
+        Ok(()) => {
+            info!("Log filter set to '{}'", directives.trim());
+            log_filter.current().unwrap_or_default().into_response()
+        }
+        Err(e) => {
+            warn!("Rejected log filter '{}': {}", directives.trim(), e);
+            (StatusCode::BAD_REQUEST, e).into_response()
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use tracing::{debug, warn};
+use chrono::Utc;
+use futures_util::future::{BoxFuture, FutureExt};
+use serde_json::{json, Map, Value};
+use tonic::body::BoxBody;
+use tonic::codegen::http;
+use tower::{Layer, Service};
+use uuid::Uuid;
+use crate::database::{find_api_key, get_connection, hash_api_key, touch_api_key, ApiKeyRecord, CacheClient, PgPool};
+use crate::errors::ApiError;
+use crate::server::{AppState, Claims, RequestId};
+use crate::utils::deadline::Deadline;
+
+static API_KEY: &str = "x-api-key";
+static CACHE_PREFIX: &str = "api_key";
+/// Seconds unknown keys stay cached, so guessing keys doesn't reach the database.
+const MISS_TTL_SECS: u64 = 5;
+/// Uses of a key are recorded at most this often by each replica.
+const TOUCH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Checks `x-api-key` metadata against the `api_keys` table. Lookups are
+/// cached, unknown keys briefly, and revoking a key clears its entry. Put the
+/// per IP rate limit in front of it to slow down guessing. The default
+/// authenticator is disabled and ignores the metadata.
+#[derive(Clone, Default)]
+pub struct ApiKeyAuthenticator {
+    state: Option<(Arc<PgPool>, CacheClient)>,
+    /// When each key was last recorded as used.
+    touched: Arc<Mutex<HashMap<Uuid, Instant>>>,
+}
+
+impl ApiKeyAuthenticator {
+    pub fn new(state: &AppState) -> Self {
+        ApiKeyAuthenticator {
+            state: Some((state.pool.clone(), state.cache.clone())),
+            touched: Arc::default(),
+        }
+    }
+
+    /// The record of `key`, from the cache when possible. Cache failures fall
+    /// back to the database.
+    async fn lookup(pool: &PgPool, cache: &CacheClient, key: &str, deadline: Deadline) -> Result<Option<ApiKeyRecord>, ApiError> {
+        let key_hash = hash_api_key(key);
+        let cache_key = cache.generate_cache_key(CACHE_PREFIX, &key_hash);
+        if let Ok(Some(record)) = cache.get_cache::<Option<ApiKeyRecord>>(deadline, &cache_key).await {
+            return Ok(record);
+        }
+
+        let mut conn = get_connection(pool, deadline).await?;
+        let record = find_api_key(&mut conn, &key_hash).await?;
+        let _ = match &record {
+            Some(_) => cache.set_cache(deadline, &cache_key, &record).await,
+            None => cache.set_cache_for(deadline, &cache_key, &record, MISS_TTL_SECS).await,
+        };
+
+        Ok(record)
+    }
+
+    /// Clears the cached record of a key, so a revoked key stops working right away.
+    pub(crate) async fn invalidate(cache: &CacheClient, deadline: Deadline, key_hash: &str) -> Result<(), ApiError> {
+        cache.invalid_cache(deadline, CACHE_PREFIX, &key_hash).await
+    }
+
+    /// Whether the use of `id` should be written, at most once per [`TOUCH_INTERVAL`].
+    fn should_touch(&self, id: Uuid) -> bool {
+        let mut touched = self.touched.lock().unwrap();
+        let now = Instant::now();
+        if touched.get(&id).is_some_and(|at| now.duration_since(*at) < TOUCH_INTERVAL) {
+            return false;
+        }
+        if touched.len() >= 10_000 {
+            touched.retain(|_, at| now.duration_since(*at) < TOUCH_INTERVAL);
+        }
+
+        touched.insert(id, now);
+        true
+    }
+
+    /// The claims of the caller, `None` when the request has no API key.
+    pub async fn authenticate(&self, headers: &http::HeaderMap) -> Result<Option<Claims>, ApiError> {
+        let (pool, cache) = match &self.state {
+            Some(state) => state,
+            None => return Ok(None),
+        };
+        let key = match headers.get(API_KEY) {
+            Some(key) => key.to_str().map_err(|_| ApiError::Unauthenticated("invalid API key".to_string()))?,
+            None => return Ok(None),
+        };
+
+        let record = ApiKeyAuthenticator::lookup(pool, cache, key, Deadline::from_headers(headers))
+            .await?
+            .ok_or_else(|| ApiError::Unauthenticated("invalid API key".to_string()))?;
+        if !record.is_active(Utc::now()) {
+            return Err(ApiError::Unauthenticated(format!("API key {} is revoked or expired", record.prefix)));
+        }
+        debug!("Authenticated API key {} of {}", record.prefix, record.owner);
+
+        if self.should_touch(record.id) {
+            let pool = pool.clone();
+            let id = record.id;
+            tokio::spawn(RequestId::propagate(async move {
+                if let Err(e) = touch_api_key(&pool, id).await {
+                    warn!("Couldn't record the use of API key {}: {}", id, e);
+                }
+            }));
+        }
+
+        let mut claims = Map::new();
+        claims.insert("sub".to_string(), Value::String(record.owner.clone()));
+        claims.insert("scope".to_string(), Value::String(record.scopes.join(" ")));
+        claims.insert("api_key_id".to_string(), json!(record.id));
+        Ok(Some(Claims(claims)))
+    }
+}
+
+/// Authenticates requests carrying an `x-api-key` and puts [`Claims`] into
+/// their extensions: `sub` is the key's owner, `scope` its scopes and
+/// `api_key_id` its id. Invalid keys are rejected with `UNAUTHENTICATED`.
+#[derive(Clone, Default)]
+pub struct ApiKeyLayer {
+    authenticator: ApiKeyAuthenticator,
+}
+
+impl ApiKeyLayer {
+    pub fn new(authenticator: ApiKeyAuthenticator) -> Self {
+        ApiKeyLayer { authenticator }
+    }
+}
+
+impl<S> Layer<S> for ApiKeyLayer {
+    type Service = ApiKeyService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ApiKeyService {
+            inner,
+            authenticator: self.authenticator.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ApiKeyService<S> {
+    inner: S,
+    authenticator: ApiKeyAuthenticator,
+}
+
+impl<S, B> Service<http::Request<B>> for ApiKeyService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    B: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<B>) -> Self::Future {
+        if self.authenticator.state.is_none() || !request.headers().contains_key(API_KEY) {
+            return self.inner.call(request).boxed();
+        }
+
+        // The inner service was polled ready, keep it and leave a fresh clone in its place
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let authenticator = self.authenticator.clone();
+
+        async move {
+            match authenticator.authenticate(request.headers()).await {
+                Ok(Some(claims)) => {
+                    request.extensions_mut().insert(claims);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    warn!("Rejected {}: {}", request.uri().path(), e);
+                    return Ok(tonic::Status::from(e).to_http());
+                }
+            }
+
+            inner.call(request).await
+        }.boxed()
+    }
+}
@@ -0,0 +1,315 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use tracing::{info, warn};
+use futures_util::future::{BoxFuture, FutureExt};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde_json::{Map, Value};
+use tokio::task::JoinHandle;
+use tonic::body::BoxBody;
+use tonic::codegen::http;
+use tower::{Layer, Service};
+use crate::config::AuthOptions;
+use crate::errors::ApiError;
+use crate::report_error;
+use crate::server::ShutdownTrigger;
+
+/// Signature algorithms accepted on tokens.
+const ALGORITHMS: &[Algorithm] = &[Algorithm::RS256, Algorithm::ES256, Algorithm::EdDSA];
+const JWKS_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Claims of a validated JWT. Inserted into the request extensions, read
+/// them in handlers with [`Claims::from_request`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Claims(pub Map<String, Value>);
+
+impl Claims {
+    pub fn from_request<T>(request: &tonic::Request<T>) -> Option<&Self> {
+        request.extensions().get::<Claims>()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.0.get(name)
+    }
+
+    pub fn subject(&self) -> Option<&str> {
+        self.get("sub").and_then(Value::as_str)
+    }
+
+    /// OAuth scopes, from the space separated `scope` claim or the `scp` list.
+    pub fn scopes(&self) -> Vec<&str> {
+        match self.get("scope").or_else(|| self.get("scp")) {
+            Some(Value::String(scopes)) => scopes.split_whitespace().collect(),
+            Some(Value::Array(scopes)) => scopes.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Entries of the `roles` claim.
+    pub fn roles(&self) -> Vec<&str> {
+        match self.get("roles") {
+            Some(Value::Array(roles)) => roles.iter().filter_map(Value::as_str).collect(),
+            Some(Value::String(role)) => vec![role.as_str()],
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Where the JSON Web Key Set comes from.
+#[derive(Debug, Clone)]
+pub enum JwksSource {
+    File(PathBuf),
+    Url(String),
+}
+
+impl JwksSource {
+    async fn load(&self) -> Result<JwkSet, Box<dyn std::error::Error + Send + Sync>> {
+        let jwks = match self {
+            JwksSource::File(path) => serde_json::from_slice(&tokio::fs::read(path).await?)?,
+            JwksSource::Url(url) => reqwest::Client::builder()
+                .timeout(JWKS_FETCH_TIMEOUT)
+                .build()?
+                .get(url)
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?,
+        };
+
+        Ok(jwks)
+    }
+}
+
+/// A key of the JWKS, ready to check signatures.
+struct VerifyingKey {
+    id: Option<String>,
+    key: DecodingKey,
+}
+
+fn verifying_keys(jwks: &JwkSet) -> Vec<VerifyingKey> {
+    jwks.keys
+        .iter()
+        .filter_map(|jwk| match DecodingKey::from_jwk(jwk) {
+            Ok(key) => Some(VerifyingKey { id: jwk.common.key_id.clone(), key }),
+            Err(e) => {
+                warn!("Skipping JWK {}: {}", jwk.common.key_id.as_deref().unwrap_or("without kid"), e);
+                None
+            }
+        })
+        .collect()
+}
+
+struct JwtSettings {
+    /// `None` when only API keys are accepted.
+    source: Option<JwksSource>,
+    refresh_interval: Duration,
+    issuer: String,
+    audience: Vec<String>,
+    leeway: Duration,
+    /// Exact paths, and `/package.Service` prefixes of whole services.
+    public_methods: HashSet<String>,
+}
+
+/// Validates `authorization: Bearer` JWTs: signature against the JWKS,
+/// issuer, audience and expiry. The default authenticator is disabled and
+/// lets every request through.
+#[derive(Clone, Default)]
+pub struct JwtAuthenticator {
+    settings: Option<Arc<JwtSettings>>,
+    keys: Arc<RwLock<Vec<VerifyingKey>>>,
+}
+
+impl JwtAuthenticator {
+    /// Loads the JWKS, failing when it can't be read or has no usable key.
+    pub async fn new(options: &AuthOptions) -> Result<Self, Box<dyn std::error::Error>> {
+        if !options.enabled {
+            return Ok(JwtAuthenticator::default());
+        }
+
+        let source = match (&options.jwks_file, &options.jwks_url) {
+            (Some(file), _) => Some(JwksSource::File(file.clone())),
+            (None, Some(url)) => Some(JwksSource::Url(url.clone())),
+            (None, None) if options.api_keys => None,
+            (None, None) => return Err("Authentication requires auth.jwks_file, auth.jwks_url or auth.api_keys".into()),
+        };
+        let mut keys = Vec::new();
+        if let Some(source) = &source {
+            let jwks = source.load().await.map_err(|e| format!("Couldn't load the JWKS from {:?}: {}", source, e))?;
+            keys = verifying_keys(&jwks);
+            if keys.is_empty() {
+                return Err("The JWKS has no usable key".into());
+            }
+            info!("Loaded {} JWT verification keys from {:?}", keys.len(), source);
+        }
+
+        let public_methods = options
+            .public_methods
+            .iter()
+            .map(|method| method.strip_suffix("/*").unwrap_or(method).to_string())
+            .collect();
+
+        Ok(JwtAuthenticator {
+            settings: Some(Arc::new(JwtSettings {
+                source,
+                refresh_interval: Duration::from_secs(options.jwks_refresh_interval_secs),
+                issuer: options.issuer.clone(),
+                audience: options.audience.clone(),
+                leeway: Duration::from_secs(options.leeway_secs),
+                public_methods,
+            })),
+            keys: Arc::new(RwLock::new(keys)),
+        })
+    }
+
+    /// Loads the JWKS again every `jwks_refresh_interval_secs` until
+    /// `shutdown` is triggered. A JWKS that can't be loaded or has no usable
+    /// key keeps the current keys.
+    pub fn spawn_refresher(&self, shutdown: ShutdownTrigger) -> Option<JoinHandle<()>> {
+        let settings = self.settings.clone()?;
+        let source = settings.source.clone()?;
+        let keys = self.keys.clone();
+
+        Some(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(settings.refresh_interval);
+            ticker.tick().await;
+
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => {
+                        let refreshed = source.load().await.and_then(|jwks| match verifying_keys(&jwks) {
+                            refreshed if refreshed.is_empty() => Err("The JWKS has no usable key".into()),
+                            refreshed => Ok(refreshed),
+                        });
+                        match refreshed {
+                            Ok(refreshed) => *keys.write().unwrap() = refreshed,
+                            Err(e) => {
+                                warn!("Failed to refresh the JWKS, keeping the current keys");
+                                report_error(e.as_ref());
+                            }
+                        }
+                    }
+                    _ = shutdown.triggered() => break,
+                }
+            }
+        }))
+    }
+
+    fn is_public(settings: &JwtSettings, path: &str) -> bool {
+        settings.public_methods.contains(path)
+            || path.rsplit_once('/').is_some_and(|(service, _)| settings.public_methods.contains(service))
+    }
+
+    /// Checks the bearer token of a request to `path`. Returns the claims,
+    /// or `None` when authentication is disabled or the method is public.
+    pub fn authenticate(&self, path: &str, headers: &http::HeaderMap) -> Result<Option<Claims>, ApiError> {
+        let settings = match &self.settings {
+            Some(settings) if !JwtAuthenticator::is_public(settings, path) => settings,
+            _ => return Ok(None),
+        };
+
+        let token = headers
+            .get(http::header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer ").or_else(|| value.strip_prefix("bearer ")))
+            .map(str::trim)
+            .ok_or_else(|| ApiError::Unauthenticated(format!("{} requires credentials", path)))?;
+        if settings.source.is_none() {
+            return Err(ApiError::InvalidToken("bearer tokens aren't accepted, use an API key".to_string()));
+        }
+
+        let header = jsonwebtoken::decode_header(token).map_err(|e| ApiError::InvalidToken(e.to_string()))?;
+        if !ALGORITHMS.contains(&header.alg) {
+            return Err(ApiError::InvalidToken(format!("unsupported algorithm {:?}", header.alg)));
+        }
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&settings.issuer]);
+        validation.set_audience(&settings.audience);
+        validation.set_required_spec_claims(&["exp", "iss", "aud"]);
+        validation.leeway = settings.leeway.as_secs();
+
+        let keys = self.keys.read().unwrap();
+        let candidates = keys.iter().filter(|key| header.kid.is_none() || key.id == header.kid);
+
+        let mut error = ApiError::InvalidToken("no key matches the token".to_string());
+        for key in candidates {
+            match jsonwebtoken::decode::<Map<String, Value>>(token, &key.key, &validation) {
+                Ok(data) => return Ok(Some(Claims(data.claims))),
+                Err(e) => error = ApiError::InvalidToken(e.to_string()),
+            }
+        }
+
+        Err(error)
+    }
+}
+
+/// Rejects requests without a valid bearer token with `UNAUTHENTICATED` and
+/// puts the [`Claims`] of valid ones into the request extensions. Requests
+/// the [`ApiKeyLayer`](crate::server::ApiKeyLayer) already authenticated pass.
+#[derive(Clone, Default)]
+pub struct JwtAuthLayer {
+    authenticator: JwtAuthenticator,
+}
+
+impl JwtAuthLayer {
+    pub fn new(authenticator: JwtAuthenticator) -> Self {
+        JwtAuthLayer { authenticator }
+    }
+}
+
+impl<S> Layer<S> for JwtAuthLayer {
+    type Service = JwtAuthService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        JwtAuthService {
+            inner,
+            authenticator: self.authenticator.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct JwtAuthService<S> {
+    inner: S,
+    authenticator: JwtAuthenticator,
+}
+
+impl<S, B> Service<http::Request<B>> for JwtAuthService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<B>) -> Self::Future {
+        // Already authenticated with an API key
+        if request.extensions().get::<Claims>().is_some() {
+            return self.inner.call(request).boxed();
+        }
+
+        match self.authenticator.authenticate(request.uri().path(), request.headers()) {
+            Ok(Some(claims)) => {
+                request.extensions_mut().insert(claims);
+            }
+            Ok(None) => {}
+            Err(e) => {
+                warn!("Rejected {}: {}", request.uri().path(), e);
+                let response = tonic::Status::from(e).to_http();
+                return async move { Ok(response) }.boxed();
+            }
+        }
+
+        self.inner.call(request).boxed()
+    }
+}
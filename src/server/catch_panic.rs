@@ -0,0 +1,225 @@
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::cell::RefCell;
+use std::fmt;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::Once;
+use std::task::{Context, Poll};
+
+use tracing::{error, field};
+use futures_util::future::{BoxFuture, FutureExt};
+use tonic::body::BoxBody;
+use tonic::codegen::{http, Body, Bytes};
+use tower::{Layer, Service};
+use crate::errors::ApiError;
+use crate::metrics::{metrics, MethodLabels};
+
+static PANIC_HOOK: Once = Once::new();
+
+tokio::task_local! {
+    /// Set while [`CatchPanicService`] runs the inner service, which makes the
+    /// panic hook keep the location and backtrace of a panic for it to log.
+    static CAUGHT_PANIC: RefCell<Option<(String, Backtrace)>>;
+}
+
+/// Chains a hook in front of the current one. Panics that a
+/// [`CatchPanicService`] is about to catch are recorded for it and logged
+/// once, by the service; every other panic goes to the current hook.
+fn install_panic_hook() {
+    PANIC_HOOK.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let recorded = CAUGHT_PANIC.try_with(|caught| {
+                let location = info.location().map(ToString::to_string).unwrap_or_default();
+                *caught.borrow_mut() = Some((location, Backtrace::force_capture()));
+            });
+            if recorded.is_err() {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// A caught panic. Its location and backtrace are logged as fields of their
+/// own, see [`record_panic`].
+#[derive(Debug)]
+struct Panic {
+    method: String,
+    message: String,
+    location: Option<(String, Backtrace)>,
+}
+
+impl Panic {
+    fn new(method: String, payload: Box<dyn Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&'static str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => "Box<dyn Any>".to_string(),
+            },
+        };
+        let location = CAUGHT_PANIC.try_with(|caught| caught.borrow_mut().take()).ok().flatten();
+        Panic { method, message, location }
+    }
+}
+
+impl fmt::Display for Panic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} panicked: {}", self.method, self.message)
+    }
+}
+
+/// Turns panics of the inner services into `InternalServerError` responses
+/// instead of resetting the stream, and counts them per method. Panics while
+/// streaming the response body end it with `INTERNAL` trailers after the
+/// messages already sent. Goes below the [`RequestIdLayer`](crate::server::RequestIdLayer)
+/// so the error carries the request ID.
+#[derive(Debug, Clone)]
+pub struct CatchPanicLayer;
+
+impl CatchPanicLayer {
+    pub fn new() -> Self {
+        install_panic_hook();
+        CatchPanicLayer
+    }
+}
+
+impl Default for CatchPanicLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for CatchPanicLayer {
+    type Service = CatchPanicService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CatchPanicService { inner }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CatchPanicService<S> {
+    inner: S,
+}
+
+impl<S, B> Service<http::Request<B>> for CatchPanicService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let method = request.uri().path().to_string();
+        // The inner service may panic in `call` as well as in its future
+        CAUGHT_PANIC.sync_scope(RefCell::new(None), || {
+            let future = match std::panic::catch_unwind(AssertUnwindSafe(|| self.inner.call(request))) {
+                Ok(future) => future,
+                Err(payload) => {
+                    let response = internal_error(method, payload);
+                    return async move { Ok(response) }.boxed();
+                }
+            };
+
+            CAUGHT_PANIC
+                .scope(RefCell::new(None), async move {
+                    match AssertUnwindSafe(future).catch_unwind().await {
+                        Ok(Ok(response)) => Ok(response.map(|inner| BoxBody::new(CatchPanicBody { inner, method, panicked: false, trailers: None }))),
+                        Ok(Err(e)) => Err(e),
+                        Err(payload) => Ok(internal_error(method, payload)),
+                    }
+                })
+                .boxed()
+        })
+    }
+}
+
+/// Response body that ends the stream with `INTERNAL` trailers when polling
+/// the inner one panics.
+struct CatchPanicBody {
+    inner: BoxBody,
+    method: String,
+    panicked: bool,
+    /// `INTERNAL` status to send once polling panicked.
+    trailers: Option<http::HeaderMap>,
+}
+
+impl CatchPanicBody {
+    fn panic(&mut self, payload: Box<dyn Any + Send>) {
+        record_panic(self.method.clone(), payload);
+        // The headers of a trailers-only response carry the status
+        let mut trailers = tonic::Status::from(ApiError::InternalServerError).to_http().into_parts().0.headers;
+        trailers.remove(http::header::CONTENT_TYPE);
+        self.panicked = true;
+        self.trailers = Some(trailers);
+    }
+}
+
+impl Body for CatchPanicBody {
+    type Data = Bytes;
+    type Error = tonic::Status;
+
+    fn poll_data(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        let this = &mut *self;
+        if this.panicked {
+            return Poll::Ready(None);
+        }
+
+        CAUGHT_PANIC.sync_scope(RefCell::new(None), || {
+            match std::panic::catch_unwind(AssertUnwindSafe(|| Pin::new(&mut this.inner).poll_data(cx))) {
+                Ok(poll) => poll,
+                Err(payload) => {
+                    this.panic(payload);
+                    Poll::Ready(None)
+                }
+            }
+        })
+    }
+
+    fn poll_trailers(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<Option<http::HeaderMap>, Self::Error>> {
+        let this = &mut *self;
+        if this.panicked {
+            return Poll::Ready(Ok(this.trailers.take()));
+        }
+
+        CAUGHT_PANIC.sync_scope(RefCell::new(None), || {
+            match std::panic::catch_unwind(AssertUnwindSafe(|| Pin::new(&mut this.inner).poll_trailers(cx))) {
+                Ok(poll) => poll,
+                Err(payload) => {
+                    this.panic(payload);
+                    Poll::Ready(Ok(this.trailers.take()))
+                }
+            }
+        })
+    }
+
+    fn is_end_stream(&self) -> bool {
+        match self.panicked {
+            true => self.trailers.is_none(),
+            false => self.inner.is_end_stream(),
+        }
+    }
+}
+
+fn record_panic(method: String, payload: Box<dyn Any + Send>) {
+    metrics().panics.get_or_create(&MethodLabels { method: method.clone() }).inc();
+    let panic = Panic::new(method, payload);
+    let (location, backtrace) = match &panic.location {
+        Some((location, backtrace)) => (Some(location.as_str()), Some(field::display(backtrace))),
+        None => (None, None),
+    };
+    error!(error = %panic, location, backtrace, "{}", panic);
+}
+
+fn internal_error(method: String, payload: Box<dyn Any + Send>) -> http::Response<BoxBody> {
+    record_panic(method, payload);
+    tonic::Status::from(ApiError::InternalServerError).to_http()
+}
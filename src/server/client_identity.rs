@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use tracing::{info, warn};
+use futures_util::future::{BoxFuture, FutureExt};
+use tonic::body::BoxBody;
+use tonic::codegen::http;
+use tonic::transport::server::{TcpConnectInfo, TlsConnectInfo};
+use tower::{Layer, Service};
+use x509_parser::extensions::GeneralName;
+use crate::errors::ApiError;
+
+/// The verified client certificate of an mTLS connection. Inserted into the
+/// request extensions, read it in handlers with [`ClientIdentity::from_request`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientIdentity {
+    /// Distinguished name, e.g. `CN=billing, O=Example`.
+    pub subject: String,
+    pub common_name: Option<String>,
+    /// DNS names, URIs (e.g. SPIFFE IDs), emails and IP addresses.
+    pub sans: Vec<String>,
+}
+
+impl ClientIdentity {
+    pub fn from_der(der: &[u8]) -> Option<Self> {
+        let (_, cert) = x509_parser::parse_x509_certificate(der).ok()?;
+
+        let common_name = cert
+            .subject()
+            .iter_common_name()
+            .next()
+            .and_then(|cn| cn.as_str().ok())
+            .map(str::to_string);
+
+        let mut sans = Vec::new();
+        if let Ok(Some(extension)) = cert.subject_alternative_name() {
+            for name in &extension.value.general_names {
+                match name {
+                    GeneralName::DNSName(name) | GeneralName::URI(name) | GeneralName::RFC822Name(name) => {
+                        sans.push(name.to_string())
+                    }
+                    GeneralName::IPAddress(bytes) => {
+                        let ip = match bytes.len() {
+                            4 => <[u8; 4]>::try_from(*bytes).ok().map(IpAddr::from),
+                            16 => <[u8; 16]>::try_from(*bytes).ok().map(IpAddr::from),
+                            _ => None,
+                        };
+                        sans.extend(ip.map(|ip| ip.to_string()));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Some(ClientIdentity {
+            subject: cert.subject().to_string(),
+            common_name,
+            sans,
+        })
+    }
+
+    pub fn from_request<T>(request: &tonic::Request<T>) -> Option<&Self> {
+        request.extensions().get::<ClientIdentity>()
+    }
+
+    /// Every name the client can be allowlisted under.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.subject.as_str())
+            .chain(self.common_name.as_deref())
+            .chain(self.sans.iter().map(String::as_str))
+    }
+
+    pub(crate) fn from_extensions(extensions: &http::Extensions) -> Option<Self> {
+        let certs = extensions.get::<TlsConnectInfo<TcpConnectInfo>>()?.peer_certs()?;
+        // The first certificate is the client's own, the rest is its chain
+        ClientIdentity::from_der(certs.first()?.get_ref())
+    }
+}
+
+/// Restricts methods to a set of client identities. Methods that aren't
+/// listed are open to any client.
+#[derive(Debug, Clone, Default)]
+pub struct ClientAllowlist {
+    rules: Arc<HashMap<String, Vec<String>>>,
+}
+
+impl ClientAllowlist {
+    /// Parses `;` separated rules of the form `method=name,name`. The method
+    /// is a gRPC path such as `/echo.v1.EchoService/UnaryEcho`, or
+    /// `/echo.v1.EchoService/*` for a whole service. A name matches the
+    /// certificate subject, its common name or any of its SANs.
+    pub fn parse(allowlist: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut rules = HashMap::new();
+
+        for rule in allowlist.split(';').map(str::trim).filter(|rule| !rule.is_empty()) {
+            let (method, names) = rule
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid allowlist rule '{}', expected method=name,name", rule))?;
+            let method = method.trim();
+            if !method.starts_with('/') {
+                return Err(format!("Invalid method '{}' in allowlist, expected /package.Service/Method", method).into());
+            }
+
+            let names = names
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .collect::<Vec<_>>();
+
+            info!("Restricting {} to clients: {}", method, names.join(", "));
+            rules.entry(method.to_string()).or_insert_with(Vec::new).extend(names);
+        }
+
+        Ok(ClientAllowlist { rules: Arc::new(rules) })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    fn rule(&self, path: &str) -> Option<&Vec<String>> {
+        self.rules.get(path).or_else(|| {
+            let (service, _) = path.rsplit_once('/')?;
+            self.rules.get(&format!("{}/*", service))
+        })
+    }
+
+    /// Checks whether `identity` may call the method at `path`.
+    pub fn check(&self, path: &str, identity: Option<&ClientIdentity>) -> Result<(), ApiError> {
+        let allowed = match self.rule(path) {
+            Some(allowed) => allowed,
+            None => return Ok(()),
+        };
+
+        let identity = identity
+            .ok_or_else(|| ApiError::Unauthenticated(format!("{} requires a client certificate", path)))?;
+
+        if identity.names().any(|name| allowed.iter().any(|allowed| allowed == name)) {
+            Ok(())
+        } else {
+            warn!("Client {} is not allowed to call {}", identity.subject, path);
+            Err(ApiError::PermissionDenied(format!("client is not allowed to call {}", path)))
+        }
+    }
+}
+
+/// Puts the [`ClientIdentity`] of mTLS connections into the request
+/// extensions and enforces the [`ClientAllowlist`].
+#[derive(Debug, Clone, Default)]
+pub struct ClientIdentityLayer {
+    allowlist: ClientAllowlist,
+}
+
+impl ClientIdentityLayer {
+    pub fn new(allowlist: ClientAllowlist) -> Self {
+        ClientIdentityLayer { allowlist }
+    }
+}
+
+impl<S> Layer<S> for ClientIdentityLayer {
+    type Service = ClientIdentityService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ClientIdentityService {
+            inner,
+            allowlist: self.allowlist.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ClientIdentityService<S> {
+    inner: S,
+    allowlist: ClientAllowlist,
+}
+
+impl<S, B> Service<http::Request<B>> for ClientIdentityService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<B>) -> Self::Future {
+        let identity = ClientIdentity::from_extensions(request.extensions());
+
+        if let Err(e) = self.allowlist.check(request.uri().path(), identity.as_ref()) {
+            let response = tonic::Status::from(e).to_http();
+            return async move { Ok(response) }.boxed();
+        }
+
+        if let Some(identity) = identity {
+            request.extensions_mut().insert(identity);
+        }
+        self.inner.call(request).boxed()
+    }
+}
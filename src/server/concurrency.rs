@@ -0,0 +1,290 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use tracing::{info, warn};
+use futures_util::future::{BoxFuture, FutureExt};
+use tonic::body::BoxBody;
+use tonic::codegen::http;
+use tonic::metadata::MetadataValue;
+use tower::{Layer, Service};
+use crate::config::LimitsOptions;
+use crate::errors::ApiError;
+use crate::metrics::{metrics, MethodLabels};
+use crate::server::Objectives;
+
+static RETRY_PUSHBACK: &str = "grpc-retry-pushback-ms";
+
+/// Share of the limit kept when a request is slower than the target latency,
+/// at most once per target latency.
+const BACKOFF_RATIO: f64 = 0.9;
+
+/// Marks the responses of requests shed by the [`ConcurrencyLimits`]. Unlike
+/// other `RESOURCE_EXHAUSTED` responses, such as rate limited requests, they
+/// are the server's doing.
+#[derive(Debug, Clone, Copy)]
+pub struct Shed;
+
+/// Caps the requests in flight per method. Methods without a limit, and
+/// paths that aren't served at all, are never rejected.
+#[derive(Debug, Clone, Default)]
+pub struct ConcurrencyLimits {
+    limiters: Arc<HashMap<String, Arc<Limiter>>>,
+    retry_after: Duration,
+}
+
+impl ConcurrencyLimits {
+    /// Parses `;` separated rules of the form `method=limit`. The method is a
+    /// gRPC path such as `/echo.v1.EchoService/UnaryEcho`, or
+    /// `/echo.v1.EchoService/*` to limit each method of the service.
+    pub fn parse_rules(rules: &str) -> Result<HashMap<String, usize>, Box<dyn std::error::Error>> {
+        let mut limits = HashMap::new();
+
+        for rule in rules.split(';').map(str::trim).filter(|rule| !rule.is_empty()) {
+            let (method, limit) = rule
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid limit '{}', expected method=limit", rule))?;
+            let method = method.trim();
+            if !method.starts_with('/') {
+                return Err(format!("Invalid method '{}' in limits, expected /package.Service/Method", method).into());
+            }
+            let limit = limit
+                .trim()
+                .parse::<usize>()
+                .ok()
+                .filter(|limit| *limit > 0)
+                .ok_or_else(|| format!("Invalid limit '{}' for {}, expected a positive number", limit.trim(), method))?;
+
+            limits.insert(method.to_string(), limit);
+        }
+
+        Ok(limits)
+    }
+
+    /// Builds a limiter for each of `methods`, the gRPC paths the server serves.
+    /// Adaptive limits target the latency of the method's objective, or else
+    /// `target_latency_ms`.
+    pub fn new(options: &LimitsOptions, objectives: &Objectives, methods: &[String]) -> Result<Self, Box<dyn std::error::Error>> {
+        let rules = ConcurrencyLimits::parse_rules(&options.methods)?;
+        for method in rules.keys() {
+            let service = method.strip_suffix("/*").unwrap_or(method);
+            if !methods.iter().any(|served| served == method || served.starts_with(&format!("{}/", service))) {
+                warn!("Concurrency limit set for {}, which isn't served", method);
+            }
+        }
+
+        let mut limiters = HashMap::new();
+        for method in methods {
+            let service_rule = method.rsplit_once('/').map(|(service, _)| format!("{}/*", service));
+            let max = rules
+                .get(method)
+                .or_else(|| service_rule.and_then(|rule| rules.get(&rule)))
+                .copied()
+                .unwrap_or(options.default_concurrency);
+            if max == 0 {
+                continue;
+            }
+
+            let target_latency = match objectives.get(method).and_then(|objective| objective.latency) {
+                Some((threshold, _)) => threshold,
+                None => Duration::from_millis(options.target_latency_ms),
+            };
+            let target_latency = Some(target_latency).filter(|_| options.adaptive);
+
+            info!("Limiting {} to {} concurrent requests{}", method, max, match target_latency {
+                Some(target) => format!(" (adaptive, {}ms target)", target.as_millis()),
+                None => String::new(),
+            });
+            let limiter = Limiter::new(method.clone(), max, options.min_concurrency.min(max), target_latency);
+            limiters.insert(method.clone(), Arc::new(limiter));
+        }
+
+        Ok(ConcurrencyLimits {
+            limiters: Arc::new(limiters),
+            retry_after: Duration::from_millis(options.retry_after_ms),
+        })
+    }
+
+    /// Current limit of `method`, `None` when it isn't limited.
+    pub fn limit(&self, method: &str) -> Option<usize> {
+        self.limiters.get(method).map(|limiter| limiter.limit())
+    }
+
+    fn reject(&self, method: &str) -> http::Response<BoxBody> {
+        metrics().requests_shed.get_or_create(&MethodLabels { method: method.to_string() }).inc();
+
+        let mut status = tonic::Status::from(ApiError::ResourceExhausted(format!("too many concurrent requests to {}", method)));
+        status.metadata_mut().insert(RETRY_PUSHBACK, MetadataValue::from(self.retry_after.as_millis() as u64));
+        let mut response = status.to_http();
+        response.extensions_mut().insert(Shed);
+        response
+    }
+}
+
+#[derive(Debug)]
+struct Limiter {
+    method: String,
+    max: usize,
+    min: usize,
+    /// Set when the limit adapts to latency.
+    target_latency: Option<Duration>,
+    state: Mutex<LimiterState>,
+}
+
+#[derive(Debug)]
+struct LimiterState {
+    in_flight: usize,
+    /// Fractional so it can grow by less than one request at a time.
+    limit: f64,
+    /// Requests that were in flight together complete slow together, only the
+    /// first of them backs off.
+    backed_off_at: Option<Instant>,
+}
+
+impl Limiter {
+    fn new(method: String, max: usize, min: usize, target_latency: Option<Duration>) -> Self {
+        let limiter = Limiter {
+            method,
+            max,
+            min,
+            target_latency,
+            state: Mutex::new(LimiterState { in_flight: 0, limit: max as f64, backed_off_at: None }),
+        };
+        limiter.report(max);
+        limiter
+    }
+
+    fn limit(&self) -> usize {
+        self.state.lock().unwrap().limit as usize
+    }
+
+    fn try_acquire(self: &Arc<Self>) -> Option<Permit> {
+        let mut state = self.state.lock().unwrap();
+        if state.in_flight >= state.limit as usize {
+            return None;
+        }
+
+        state.in_flight += 1;
+        Some(Permit { limiter: self.clone(), started: Instant::now() })
+    }
+
+    /// Additive increase while requests meet the target latency,
+    /// multiplicative decrease when they don't, once per target latency.
+    fn adapt(&self, latency: Duration) {
+        let target = match self.target_latency {
+            Some(target) => target,
+            None => return,
+        };
+
+        let mut state = self.state.lock().unwrap();
+        let previous = state.limit as usize;
+        if latency <= target {
+            state.limit = (state.limit + 1.0 / state.limit).min(self.max as f64);
+        } else if state.backed_off_at.map_or(true, |at| at.elapsed() >= target) {
+            state.limit = (state.limit * BACKOFF_RATIO).max(self.min as f64);
+            state.backed_off_at = Some(Instant::now());
+        }
+
+        let limit = state.limit as usize;
+        drop(state);
+        if limit != previous {
+            self.report(limit);
+        }
+    }
+
+    fn report(&self, limit: usize) {
+        metrics()
+            .concurrency_limit
+            .get_or_create(&MethodLabels { method: self.method.clone() })
+            .set(limit as i64);
+    }
+}
+
+/// A slot taken by a request in flight, given back when dropped.
+struct Permit {
+    limiter: Arc<Limiter>,
+    started: Instant,
+}
+
+impl Permit {
+    /// Feeds the request's latency to the adaptive limit. Requests that are
+    /// cancelled don't count.
+    fn complete(self) {
+        self.limiter.adapt(self.started.elapsed());
+    }
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        self.limiter.state.lock().unwrap().in_flight -= 1;
+    }
+}
+
+/// Rejects requests to methods at their concurrency limit with
+/// `RESOURCE_EXHAUSTED` and a `grpc-retry-pushback-ms` hint, without waiting
+/// for a slot. Streaming RPCs hold their slot until the response starts.
+#[derive(Debug, Clone, Default)]
+pub struct ConcurrencyLimitLayer {
+    limits: ConcurrencyLimits,
+}
+
+impl ConcurrencyLimitLayer {
+    pub fn new(limits: ConcurrencyLimits) -> Self {
+        ConcurrencyLimitLayer { limits }
+    }
+}
+
+impl<S> Layer<S> for ConcurrencyLimitLayer {
+    type Service = ConcurrencyLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ConcurrencyLimitService {
+            inner,
+            limits: self.limits.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConcurrencyLimitService<S> {
+    inner: S,
+    limits: ConcurrencyLimits,
+}
+
+impl<S, B> Service<http::Request<B>> for ConcurrencyLimitService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let method = request.uri().path();
+        let permit = match self.limits.limiters.get(method) {
+            Some(limiter) => match limiter.try_acquire() {
+                Some(permit) => Some(permit),
+                None => {
+                    let response = self.limits.reject(method);
+                    return async move { Ok(response) }.boxed();
+                }
+            },
+            None => None,
+        };
+
+        let response = self.inner.call(request);
+        async move {
+            let response = response.await;
+            if let (Some(permit), Ok(_)) = (permit, &response) {
+                permit.complete();
+            }
+            response
+        }.boxed()
+    }
+}
@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use tracing::info;
+use tonic::codegen::http::{HeaderName, HeaderValue, Method};
+use tower_http::cors::{AllowOrigin, CorsLayer};
+use crate::config::GrpcWebOptions;
+
+const DEFAULT_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+const DEFAULT_ALLOWED_HEADERS: &[&str] = &[
+    "x-grpc-web",
+    "content-type",
+    "x-user-agent",
+    "grpc-timeout",
+    "authorization",
+    "x-api-key",
+    "x-request-id",
+];
+
+/// Metadata browsers must be allowed to read for clients to see gRPC status,
+/// the structured `ApiError` payload, the request ID and the cache status of a response.
+const DEFAULT_EXPOSED_HEADERS: &[&str] = &[
+    "grpc-status",
+    "grpc-message",
+    "grpc-status-details-bin",
+    "x-tonic-error",
+    "x-request-id",
+    "x-cache",
+    "cache-control",
+];
+
+#[derive(Debug, Clone)]
+pub struct GrpcWebConfig {
+    /// `None` allows any origin.
+    pub allowed_origins: Option<Vec<String>>,
+    pub allowed_headers: Vec<String>,
+    pub exposed_headers: Vec<String>,
+}
+
+impl GrpcWebConfig {
+    /// Returns `None` unless gRPC-Web is enabled. An empty origin list or `*` allows any origin.
+    pub fn from_options(options: &GrpcWebOptions) -> Option<Self> {
+        if !options.enabled {
+            return None;
+        }
+
+        let allowed_origins = Some(options.allowed_origins.clone())
+            .filter(|origins| !origins.is_empty() && !origins.iter().any(|o| o == "*"));
+
+        Some(GrpcWebConfig {
+            allowed_origins,
+            allowed_headers: options.allowed_headers.clone(),
+            exposed_headers: options.exposed_headers.clone(),
+        })
+    }
+
+    pub fn cors_layer(&self) -> Result<CorsLayer, Box<dyn std::error::Error>> {
+        let allowed_headers = header_names(DEFAULT_ALLOWED_HEADERS, &self.allowed_headers)?;
+        let exposed_headers = header_names(DEFAULT_EXPOSED_HEADERS, &self.exposed_headers)?;
+
+        let cors = CorsLayer::new()
+            .allow_methods([Method::POST, Method::OPTIONS])
+            .allow_headers(allowed_headers)
+            .expose_headers(exposed_headers)
+            .max_age(DEFAULT_MAX_AGE);
+
+        let cors = match &self.allowed_origins {
+            Some(origins) => {
+                info!("gRPC-Web enabled for origins: {}", origins.join(", "));
+                let origins = origins
+                    .iter()
+                    .map(|origin| HeaderValue::from_str(origin))
+                    .collect::<Result<Vec<_>, _>>()?;
+                cors.allow_origin(AllowOrigin::list(origins))
+                    .allow_credentials(true)
+            }
+            None => {
+                info!("gRPC-Web enabled for any origin");
+                cors.allow_origin(AllowOrigin::any())
+            }
+        };
+
+        Ok(cors)
+    }
+}
+
+fn header_names(defaults: &[&'static str], extra: &[String]) -> Result<Vec<HeaderName>, Box<dyn std::error::Error>> {
+    let mut names = defaults
+        .iter()
+        .map(|name| HeaderName::from_static(name))
+        .collect::<Vec<_>>();
+
+    for name in extra {
+        let name = HeaderName::from_bytes(name.to_lowercase().as_bytes())?;
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    Ok(names)
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tracing::{info, warn};
+use tokio::task::JoinHandle;
+use tonic_health::server::HealthReporter;
+use tonic_health::ServingStatus;
+use crate::database::{self, record_pool_metrics, CacheClient, PgPool};
+use crate::server::ShutdownTrigger;
+
+/// The empty service name reports the health of the server as a whole.
+const SERVER_SERVICE_NAME: &str = "";
+
+/// Periodically checks Postgres and Redis and publishes the result on the
+/// `grpc.health.v1.Health` service for the server and each of `service_names`.
+/// Every service flips to `NOT_SERVING` as soon as `shutdown` is triggered and
+/// stays there while the server drains.
+pub fn spawn_health_checker(
+    mut reporter: HealthReporter,
+    service_names: Vec<&'static str>,
+    pool: Arc<PgPool>,
+    cache: CacheClient,
+    shutdown: ShutdownTrigger,
+    interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        let mut last_status = None;
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    record_pool_metrics(&pool);
+                    let status = check_dependencies(&pool, &cache, interval).await;
+                    if last_status != Some(status) {
+                        match status {
+                            ServingStatus::Serving => info!("Health status changed to SERVING"),
+                            _ => warn!("Health status changed to NOT_SERVING"),
+                        }
+                        set_status(&mut reporter, &service_names, status).await;
+                        last_status = Some(status);
+                    }
+                }
+                _ = shutdown.triggered() => {
+                    info!("Shutdown in progress, reporting NOT_SERVING");
+                    set_status(&mut reporter, &service_names, ServingStatus::NotServing).await;
+                    break;
+                }
+            }
+        }
+    })
+}
+
+async fn check_dependencies(pool: &PgPool, cache: &CacheClient, timeout: Duration) -> ServingStatus {
+    let status = DependencyStatus::check(pool, cache, timeout).await;
+    if let Some(e) = &status.postgres {
+        warn!("Health check failed for Postgres: {}", e);
+    }
+    if let Some(e) = &status.redis {
+        warn!("Health check failed for Redis: {}", e);
+    }
+
+    if status.is_ok() {
+        ServingStatus::Serving
+    } else {
+        ServingStatus::NotServing
+    }
+}
+
+/// Reachability of Postgres and Redis, with the error of each unreachable one.
+#[derive(Debug, Clone, Default)]
+pub struct DependencyStatus {
+    pub postgres: Option<String>,
+    pub redis: Option<String>,
+}
+
+impl DependencyStatus {
+    pub async fn check(pool: &PgPool, cache: &CacheClient, timeout: Duration) -> Self {
+        let postgres = match tokio::time::timeout(timeout, database::ping(pool)).await {
+            Ok(Ok(_)) => None,
+            Ok(Err(e)) => Some(e.to_string()),
+            Err(_) => Some("timed out".to_string()),
+        };
+        let redis = cache.ping(timeout).await.err().map(|e| e.to_string());
+
+        DependencyStatus { postgres, redis }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.postgres.is_none() && self.redis.is_none()
+    }
+}
+
+async fn set_status(reporter: &mut HealthReporter, service_names: &[&'static str], status: ServingStatus) {
+    for service_name in std::iter::once(&SERVER_SERVICE_NAME).chain(service_names) {
+        reporter.set_service_status(service_name, status).await;
+    }
+}
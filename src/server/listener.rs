@@ -0,0 +1,233 @@
+use std::fs;
+use std::io;
+use std::net::SocketAddr;
+use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tracing::info;
+use futures_util::{Stream, StreamExt};
+use socket2::{Domain, Protocol, Socket, Type};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::net::{TcpListener, TcpStream, UnixListener, UnixStream};
+use tokio_stream::wrappers::{TcpListenerStream, UnixListenerStream};
+use tonic::transport::server::{Connected, TcpConnectInfo};
+use crate::config::ServerOptions;
+use crate::create_socket_addr;
+
+const LISTEN_BACKLOG: i32 = 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ListenAddr {
+    /// IPv6 addresses are bound v6-only unless `v6_only` is false, so an IPv4
+    /// and an IPv6 listener can share a port.
+    Tcp { addr: SocketAddr, v6_only: bool },
+    /// `mode` is applied to the socket file before it appears at `path`, e.g. `0o660`.
+    Unix { path: PathBuf, mode: Option<u32> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListenerConfig {
+    pub addr: ListenAddr,
+    /// `None` uses TLS whenever it is configured.
+    pub tls: Option<bool>,
+}
+
+impl ListenerConfig {
+    /// Parses the configured listeners, e.g.
+    /// `0.0.0.0:50051,[::]:50051,unix:///run/grpc.sock?mode=660&tls=false`.
+    /// Without any the server keeps listening on `port` as picked by `enable_ipv6`.
+    pub fn from_options(options: &ServerOptions) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        if options.listeners.is_empty() {
+            return Ok(vec![ListenerConfig {
+                addr: ListenAddr::Tcp { addr: create_socket_addr(options.port, options.enable_ipv6), v6_only: false },
+                tls: None,
+            }]);
+        }
+
+        options.listeners.iter().map(|spec| ListenerConfig::parse(spec)).collect()
+    }
+
+    /// Parses a single listener: `host:port`, `tcp://host:port` or `unix:///path`,
+    /// optionally followed by `?tls=true|false`, `mode=<octal>` (Unix sockets)
+    /// and `v6only=true|false` (IPv6).
+    pub fn parse(spec: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let (location, query) = match spec.split_once('?') {
+            Some((location, query)) => (location, query),
+            None => (spec, ""),
+        };
+
+        let mut tls = None;
+        let mut mode = None;
+        let mut v6_only = true;
+        for param in query.split('&').filter(|param| !param.is_empty()) {
+            let (key, value) = param
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid listener option '{}' in '{}'", param, spec))?;
+            match key {
+                "tls" => tls = Some(value.parse::<bool>().map_err(|_| format!("Invalid tls value in '{}'", spec))?),
+                "mode" => mode = Some(u32::from_str_radix(value, 8).map_err(|_| format!("Invalid octal mode in '{}'", spec))?),
+                "v6only" => v6_only = value.parse::<bool>().map_err(|_| format!("Invalid v6only value in '{}'", spec))?,
+                _ => return Err(format!("Unknown listener option '{}' in '{}'", key, spec).into()),
+            }
+        }
+
+        let addr = match location.strip_prefix("unix://") {
+            Some(path) if !path.is_empty() => ListenAddr::Unix { path: PathBuf::from(path), mode },
+            Some(_) => return Err(format!("Missing socket path in '{}'", spec).into()),
+            None => {
+                if mode.is_some() {
+                    return Err(format!("mode only applies to Unix sockets in '{}'", spec).into());
+                }
+                let addr = location.strip_prefix("tcp://").unwrap_or(location);
+                let addr = addr
+                    .parse::<SocketAddr>()
+                    .map_err(|e| format!("Invalid listen address '{}': {}", addr, e))?;
+                ListenAddr::Tcp { addr, v6_only }
+            }
+        };
+
+        Ok(ListenerConfig { addr, tls })
+    }
+
+    /// Binds the listener and returns its stream of accepted connections.
+    pub fn bind(&self) -> io::Result<Incoming> {
+        match &self.addr {
+            ListenAddr::Tcp { addr, v6_only } => {
+                let socket = Socket::new(Domain::for_address(*addr), Type::STREAM, Some(Protocol::TCP))?;
+                if addr.is_ipv6() {
+                    socket.set_only_v6(*v6_only)?;
+                }
+                socket.set_reuse_address(true)?;
+                socket.set_nonblocking(true)?;
+                socket.bind(&(*addr).into())?;
+                socket.listen(LISTEN_BACKLOG)?;
+
+                let listener = TcpListener::from_std(socket.into())?;
+                info!("Listening on {}", listener.local_addr()?);
+                Ok(TcpListenerStream::new(listener)
+                    .map(|stream| stream.map(Connection::Tcp))
+                    .boxed())
+            }
+            ListenAddr::Unix { path, mode } => {
+                remove_stale_socket(path)?;
+                let listener = match mode {
+                    Some(mode) => bind_unix_with_mode(path, *mode)?,
+                    None => UnixListener::bind(path)?,
+                };
+                info!("Listening on unix://{}", path.display());
+                Ok(UnixListenerStream::new(listener)
+                    .map(|stream| stream.map(Connection::Unix))
+                    .boxed())
+            }
+        }
+    }
+
+    /// Removes the socket file of a Unix listener once the server is done with it.
+    pub fn cleanup(&self) {
+        if let ListenAddr::Unix { path, .. } = &self.addr {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+impl std::fmt::Display for ListenerConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.addr {
+            ListenAddr::Tcp { addr, .. } => write!(f, "{}", addr),
+            ListenAddr::Unix { path, .. } => write!(f, "unix://{}", path.display()),
+        }
+    }
+}
+
+/// A socket left behind by a previous run would make the bind fail.
+fn remove_stale_socket(path: &Path) -> io::Result<()> {
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_socket() => fs::remove_file(path),
+        Ok(_) => Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} exists and is not a socket", path.display()),
+        )),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Binds the socket in a private directory next to `path` and only moves it
+/// into place once `mode` is set, so nobody can connect while the socket
+/// still has the permissions of the umask.
+fn bind_unix_with_mode(path: &Path, mode: u32) -> io::Result<UnixListener> {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let staging = parent.join(format!(".{}.{:08x}", std::process::id(), rand::random::<u32>()));
+    fs::DirBuilder::new().mode(0o700).create(&staging)?;
+
+    let staged = staging.join("sock");
+    let bound = UnixListener::bind(&staged).and_then(|listener| {
+        fs::set_permissions(&staged, fs::Permissions::from_mode(mode))?;
+        fs::rename(&staged, path)?;
+        Ok(listener)
+    });
+    let _ = fs::remove_file(&staged);
+    let _ = fs::remove_dir(&staging);
+    bound
+}
+
+pub type Incoming = Pin<Box<dyn Stream<Item = io::Result<Connection>> + Send>>;
+
+/// A connection accepted on any kind of listener, so every listener can be
+/// served by the same router.
+pub enum Connection {
+    Tcp(TcpStream),
+    Unix(UnixStream),
+}
+
+impl Connected for Connection {
+    // Unix sockets have no peer address, `Request::remote_addr` is `None` for them
+    type ConnectInfo = TcpConnectInfo;
+
+    fn connect_info(&self) -> Self::ConnectInfo {
+        match self {
+            Connection::Tcp(stream) => stream.connect_info(),
+            Connection::Unix(_) => TcpConnectInfo {
+                local_addr: None,
+                remote_addr: None,
+            },
+        }
+    }
+}
+
+impl AsyncRead for Connection {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        match self.get_mut() {
+            Connection::Tcp(stream) => Pin::new(stream).poll_read(cx, buf),
+            Connection::Unix(stream) => Pin::new(stream).poll_read(cx, buf),
+        }
+    }
+}
+
+impl AsyncWrite for Connection {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        match self.get_mut() {
+            Connection::Tcp(stream) => Pin::new(stream).poll_write(cx, buf),
+            Connection::Unix(stream) => Pin::new(stream).poll_write(cx, buf),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.get_mut() {
+            Connection::Tcp(stream) => Pin::new(stream).poll_flush(cx),
+            Connection::Unix(stream) => Pin::new(stream).poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.get_mut() {
+            Connection::Tcp(stream) => Pin::new(stream).poll_shutdown(cx),
+            Connection::Unix(stream) => Pin::new(stream).poll_shutdown(cx),
+        }
+    }
+}
@@ -1,4 +1,40 @@
+mod access_log;
+mod admin;
+mod api_key;
+mod auth;
+mod catch_panic;
+mod client_identity;
+mod concurrency;
+mod grpc_web;
+mod health;
+mod listener;
+mod policy;
+mod rate_limit;
+mod registry;
+mod request_id;
 mod server;
+mod shutdown;
+mod slo;
+mod span;
+mod tls;
 pub mod services;
 
+pub use self::access_log::*;
+pub use self::admin::*;
+pub use self::api_key::*;
+pub use self::auth::*;
+pub use self::catch_panic::*;
+pub use self::client_identity::*;
+pub use self::concurrency::*;
+pub use self::grpc_web::*;
+pub use self::health::*;
+pub use self::listener::*;
+pub use self::policy::*;
+pub use self::rate_limit::*;
+pub use self::registry::*;
+pub use self::request_id::*;
 pub use self::server::*;
+pub use self::shutdown::*;
+pub use self::slo::*;
+pub use self::span::*;
+pub use self::tls::*;
@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use tracing::{info, warn};
+use futures_util::future::{BoxFuture, FutureExt};
+use serde::Deserialize;
+use tonic::body::BoxBody;
+use tonic::codegen::http;
+use tower::{Layer, Service};
+use crate::config::read_file;
+use crate::errors::ApiError;
+use crate::metrics::{metrics, PolicyLabels};
+use crate::server::{Claims, ClientIdentity};
+
+/// What happens to methods no rule matches.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DefaultAction {
+    #[default]
+    Allow,
+    Deny,
+}
+
+/// Who may call a method. Without `public`, callers must be authenticated,
+/// hold one of `roles` when any is listed, and every scope of `scopes`. A
+/// client certificate named in `clients` is enough on its own, and only those
+/// certificates may call a method whose rule lists nothing else.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyRule {
+    /// gRPC path such as `/echo.v1.EchoService/UnaryEcho`, or
+    /// `/echo.v1.EchoService/*` for every method of the service.
+    pub method: String,
+    #[serde(default)]
+    pub public: bool,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    /// Certificate subjects, common names or SANs, as in the client allowlist.
+    #[serde(default)]
+    pub clients: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PolicyFile {
+    #[serde(default)]
+    default: DefaultAction,
+    #[serde(default)]
+    rules: Vec<PolicyRule>,
+}
+
+/// Per-method authorization rules, checked against the [`Claims`] and the
+/// [`ClientIdentity`] of the caller. An exact method rule wins over a service
+/// wildcard. The default policy allows everything.
+///
+/// ```toml
+/// default = "deny"
+///
+/// [[rules]]
+/// method = "/grpc.health.v1.Health/*"
+/// public = true
+///
+/// [[rules]]
+/// method = "/echo.v1.EchoService/*"
+/// roles = ["admin", "support"]
+/// scopes = ["echo:read"]
+/// clients = ["CN=billing, O=Example"]
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Policy {
+    default: DefaultAction,
+    rules: Arc<HashMap<String, PolicyRule>>,
+    dry_run: bool,
+}
+
+impl Policy {
+    pub fn new(default: DefaultAction, rules: Vec<PolicyRule>) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut by_method = HashMap::new();
+        for rule in rules {
+            if !rule.method.starts_with('/') {
+                return Err(format!("Invalid method '{}' in policy, expected /package.Service/Method", rule.method).into());
+            }
+            if rule.public && !(rule.roles.is_empty() && rule.scopes.is_empty() && rule.clients.is_empty()) {
+                return Err(format!("Policy rule for {} is public but lists roles, scopes or clients", rule.method).into());
+            }
+            if let Some(rule) = by_method.insert(rule.method.clone(), rule) {
+                return Err(format!("Policy has several rules for {}", rule.method).into());
+            }
+        }
+
+        Ok(Policy { default, rules: Arc::new(by_method), dry_run: false })
+    }
+
+    /// Reads the rules from a TOML or YAML file.
+    pub fn load(file: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let file: PolicyFile = serde_json::from_value(read_file(file)?)?;
+        Policy::new(file.default, file.rules)
+    }
+
+    /// Only logs the requests that would be denied.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    fn rule(&self, method: &str) -> Option<&PolicyRule> {
+        self.rules.get(method).or_else(|| {
+            let (service, _) = method.rsplit_once('/')?;
+            self.rules.get(&format!("{}/*", service))
+        })
+    }
+
+    /// Checks whether the caller with `claims` and `identity` may call `method`.
+    pub fn check(&self, method: &str, claims: Option<&Claims>, identity: Option<&ClientIdentity>) -> Result<(), ApiError> {
+        let rule = match self.rule(method) {
+            Some(rule) => rule,
+            None if self.default == DefaultAction::Allow => return Ok(()),
+            None => return Err(ApiError::PermissionDenied(format!("no policy allows calling {}", method))),
+        };
+        if rule.public {
+            return Ok(());
+        }
+        if identity.is_some_and(|identity| identity.names().any(|name| rule.clients.iter().any(|client| client == name))) {
+            return Ok(());
+        }
+
+        // Rules listing only clients don't accept claims
+        let accepts_claims = rule.clients.is_empty() || !(rule.roles.is_empty() && rule.scopes.is_empty());
+        let claims = match claims {
+            Some(claims) if accepts_claims => claims,
+            None if accepts_claims || identity.is_none() => {
+                return Err(ApiError::Unauthenticated(format!("{} requires credentials", method)))
+            }
+            _ => return Err(ApiError::PermissionDenied(format!("{} requires the client certificate of {}", method, rule.clients.join(", ")))),
+        };
+        let roles = claims.roles();
+        if !rule.roles.is_empty() && !rule.roles.iter().any(|role| roles.contains(&role.as_str())) {
+            return Err(ApiError::PermissionDenied(format!("{} requires one of the roles {}", method, rule.roles.join(", "))));
+        }
+        let scopes = claims.scopes();
+        if let Some(missing) = rule.scopes.iter().find(|scope| !scopes.contains(&scope.as_str())) {
+            return Err(ApiError::PermissionDenied(format!("{} requires the scope {}", method, missing)));
+        }
+
+        Ok(())
+    }
+
+    /// Methods on which the policy and `public_methods`, the methods the auth
+    /// layers let through without credentials, disagree. Either way callers
+    /// would be rejected by one of them.
+    pub fn check_public_methods(&self, public_methods: &[String]) -> Vec<String> {
+        let is_public = |method: &str| {
+            public_methods.iter().any(|public| public == method)
+                || method.rsplit_once('/').is_some_and(|(service, _)| public_methods.contains(&format!("{}/*", service)))
+        };
+
+        let mut errors = Vec::new();
+        for rule in self.rules.values().filter(|rule| rule.public && !is_public(&rule.method)) {
+            errors.push(format!("{} is public in the policy but not in auth.public_methods", rule.method));
+        }
+        for method in public_methods {
+            let service = method.strip_suffix("/*");
+            let rules = self.rules.values().filter(|rule| {
+                rule.method == *method || service.is_some_and(|service| rule.method.starts_with(&format!("{}/", service)))
+            });
+            for rule in rules.filter(|rule| !rule.public) {
+                errors.push(format!("{} is in auth.public_methods but the policy requires credentials for {}", method, rule.method));
+            }
+            if self.rule(method).is_none() && self.default == DefaultAction::Deny {
+                errors.push(format!("{} is in auth.public_methods but the policy denies it by default", method));
+            }
+        }
+
+        errors.sort();
+        errors
+    }
+
+    fn record(&self, method: &str, decision: &'static str) {
+        let rule = self.rule(method).map_or("default", |rule| rule.method.as_str()).to_string();
+        metrics().policy_decisions.get_or_create(&PolicyLabels { rule, decision }).inc();
+    }
+}
+
+/// Enforces the [`Policy`] on every request. Denied requests get
+/// `PERMISSION_DENIED`, or `UNAUTHENTICATED` when they carry no credentials.
+#[derive(Debug, Clone, Default)]
+pub struct PolicyLayer {
+    policy: Policy,
+}
+
+impl PolicyLayer {
+    pub fn new(policy: Policy) -> Self {
+        if policy.dry_run && !policy.rules.is_empty() {
+            info!("Authorization policy in dry-run mode, denials are only logged");
+        }
+        PolicyLayer { policy }
+    }
+}
+
+impl<S> Layer<S> for PolicyLayer {
+    type Service = PolicyService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        PolicyService {
+            inner,
+            policy: self.policy.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PolicyService<S> {
+    inner: S,
+    policy: Policy,
+}
+
+impl<S, B> Service<http::Request<B>> for PolicyService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let method = request.uri().path();
+        let claims = request.extensions().get::<Claims>();
+        let identity = request.extensions().get::<ClientIdentity>();
+
+        match self.policy.check(method, claims, identity) {
+            Ok(()) => self.policy.record(method, "allowed"),
+            Err(e) => {
+                let caller = claims
+                    .and_then(Claims::subject)
+                    .or(identity.map(|identity| identity.subject.as_str()))
+                    .unwrap_or("anonymous caller");
+                if self.policy.dry_run {
+                    warn!("Policy would deny {} calling {}: {}", caller, method, e);
+                    self.policy.record(method, "dry_run_denied");
+                } else {
+                    warn!("Policy denied {} calling {}: {}", caller, method, e);
+                    self.policy.record(method, "denied");
+                    let response = tonic::Status::from(e).to_http();
+                    return async move { Ok(response) }.boxed();
+                }
+            }
+        }
+
+        self.inner.call(request).boxed()
+    }
+}
@@ -0,0 +1,381 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tracing::{info, warn};
+use futures_util::future::{BoxFuture, FutureExt};
+use tonic::body::BoxBody;
+use tonic::codegen::http;
+use tonic::metadata::{MetadataMap, MetadataValue};
+use tonic::transport::server::{TcpConnectInfo, TlsConnectInfo};
+use tower::{Layer, Service};
+use crate::config::RateLimitOptions;
+use crate::database::CacheClient;
+use crate::errors::ApiError;
+use crate::metrics::{metrics, RateLimitLabels};
+use crate::server::{Claims, ClientIdentity};
+use crate::utils::deadline::Deadline;
+
+/// Method recorded for the per address quota, which counts every method.
+static ANY_METHOD: &str = "*";
+static LIMIT: &str = "x-ratelimit-limit";
+static REMAINING: &str = "x-ratelimit-remaining";
+static RESET: &str = "x-ratelimit-reset";
+static RETRY_PUSHBACK: &str = "grpc-retry-pushback-ms";
+
+/// Requests a client may make per window, e.g. `100/1s` or `600/1m`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quota {
+    pub limit: u64,
+    pub window: Duration,
+}
+
+impl Quota {
+    pub fn parse(quota: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let (limit, window) = quota
+            .trim()
+            .split_once('/')
+            .ok_or_else(|| format!("Invalid quota '{}', expected requests/window such as 100/1m", quota))?;
+        let limit = limit
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .filter(|limit| *limit > 0)
+            .ok_or_else(|| format!("Invalid quota '{}', expected a positive number of requests", quota))?;
+
+        let window = window.trim();
+        let unit_start = window.find(|c: char| !c.is_ascii_digit()).unwrap_or(window.len());
+        let (count, unit) = window.split_at(unit_start);
+        let count = match count {
+            "" => 1,
+            count => count.parse::<u64>()?,
+        };
+        let unit = match unit {
+            "ms" => Duration::from_millis(1),
+            "s" => Duration::from_secs(1),
+            "m" => Duration::from_secs(60),
+            "h" => Duration::from_secs(3600),
+            _ => return Err(format!("Invalid window '{}', expected a duration in ms, s, m or h", window).into()),
+        };
+        if count == 0 {
+            return Err(format!("Invalid window '{}', expected a positive duration", window).into());
+        }
+        let window = u32::try_from(count)
+            .ok()
+            .and_then(|count| unit.checked_mul(count))
+            .ok_or_else(|| format!("Invalid window '{}', too long", window))?;
+
+        Ok(Quota { limit, window })
+    }
+}
+
+/// Hits of a client in the current window and the one before it.
+#[derive(Debug, Clone, Copy)]
+struct Hits {
+    current: u64,
+    previous: u64,
+    /// Time elapsed in the current window.
+    elapsed: Duration,
+}
+
+/// Outcome of counting a request against its quota.
+#[derive(Debug, Clone, Copy)]
+struct Decision {
+    quota: Quota,
+    allowed: bool,
+    remaining: u64,
+    /// Time until the client may make another request.
+    reset: Duration,
+}
+
+impl Decision {
+    /// Sliding window: the previous window's hits count in proportion to
+    /// how much of it still overlaps the last `window`.
+    fn new(quota: Quota, hits: Hits) -> Self {
+        let window = quota.window.as_secs_f64();
+        let elapsed = hits.elapsed.as_secs_f64();
+        let previous = hits.previous as f64 * (window - elapsed) / window;
+        let estimate = (previous + hits.current as f64).ceil() as u64;
+
+        let reset = if hits.current >= quota.limit {
+            window - elapsed
+        } else if hits.previous == 0 {
+            0.0
+        } else {
+            // Until enough of the previous window has slid out to make room
+            let room = (quota.limit - hits.current) as f64;
+            (window - elapsed - room * window / hits.previous as f64).max(0.0)
+        };
+
+        Decision {
+            quota,
+            allowed: estimate <= quota.limit,
+            remaining: quota.limit.saturating_sub(estimate),
+            reset: Duration::from_secs_f64(reset),
+        }
+    }
+
+    fn insert_metadata(&self, metadata: &mut MetadataMap) {
+        metadata.insert(LIMIT, MetadataValue::from(self.quota.limit));
+        metadata.insert(REMAINING, MetadataValue::from(self.remaining));
+        metadata.insert(RESET, MetadataValue::from(self.reset.as_secs_f64().ceil() as u64));
+    }
+}
+
+/// Per-method quotas shared by every replica through Redis. Each client is
+/// counted separately, identified by its API key or JWT subject, its client
+/// certificate or its IP address, in that order. The optional per address
+/// quota counts every request of an IP address before authentication, failed
+/// attempts included.
+#[derive(Clone, Default)]
+pub struct RateLimits {
+    quotas: Arc<HashMap<String, Quota>>,
+    per_ip: Option<Quota>,
+    cache: Option<CacheClient>,
+    fail_open: bool,
+    timeout: Duration,
+}
+
+impl RateLimits {
+    /// Parses `;` separated rules of the form `method=quota`. The method is a
+    /// gRPC path such as `/echo.v1.EchoService/UnaryEcho`, or
+    /// `/echo.v1.EchoService/*` for each method of the service. See
+    /// [`Quota::parse`] for the quota.
+    pub fn parse_rules(rules: &str) -> Result<HashMap<String, Quota>, Box<dyn std::error::Error>> {
+        let mut quotas = HashMap::new();
+
+        for rule in rules.split(';').map(str::trim).filter(|rule| !rule.is_empty()) {
+            let (method, quota) = rule
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid rate limit '{}', expected method=requests/window", rule))?;
+            let method = method.trim();
+            if !method.starts_with('/') {
+                return Err(format!("Invalid method '{}' in rate limits, expected /package.Service/Method", method).into());
+            }
+
+            quotas.insert(method.to_string(), Quota::parse(quota)?);
+        }
+
+        Ok(quotas)
+    }
+
+    /// Resolves the quota of each of `methods`, the gRPC paths the server serves.
+    pub fn new(options: &RateLimitOptions, methods: &[String], cache: CacheClient) -> Result<Self, Box<dyn std::error::Error>> {
+        let rules = RateLimits::parse_rules(&options.methods)?;
+        let default = match options.default_quota.as_str() {
+            "" => None,
+            quota => Some(Quota::parse(quota)?),
+        };
+        let per_ip = match options.per_ip_quota.as_str() {
+            "" => None,
+            quota => Some(Quota::parse(quota)?),
+        };
+        if let Some(quota) = per_ip {
+            info!("Rate limiting each IP address to {} requests per {:?}", quota.limit, quota.window);
+        }
+        for method in rules.keys() {
+            let service = method.strip_suffix("/*").unwrap_or(method);
+            if !methods.iter().any(|served| served == method || served.starts_with(&format!("{}/", service))) {
+                warn!("Rate limit set for {}, which isn't served", method);
+            }
+        }
+
+        let mut quotas = HashMap::new();
+        for method in methods {
+            let service_rule = method.rsplit_once('/').map(|(service, _)| format!("{}/*", service));
+            let quota = rules
+                .get(method)
+                .or_else(|| service_rule.and_then(|rule| rules.get(&rule)))
+                .copied()
+                .or(default);
+
+            if let Some(quota) = quota {
+                info!("Rate limiting {} to {} requests per {:?} per client", method, quota.limit, quota.window);
+                quotas.insert(method.clone(), quota);
+            }
+        }
+
+        Ok(RateLimits {
+            quotas: Arc::new(quotas),
+            per_ip,
+            cache: Some(cache),
+            fail_open: options.fail_open,
+            timeout: Duration::from_millis(options.redis_timeout_ms),
+        })
+    }
+
+    /// Quota of `method`, `None` when it isn't rate limited.
+    pub fn quota(&self, method: &str) -> Option<Quota> {
+        self.quotas.get(method).copied()
+    }
+
+    async fn check(&self, cache: &CacheClient, method: &str, quota: Quota, client: &str) -> Result<Decision, ApiError> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let window = quota.window.as_millis();
+        let index = now.as_millis() / window;
+
+        let key = |index: u128| format!("ratelimit:{}:{}:{}", method, client, index);
+        let (current, previous) = cache
+            .count_hit(Deadline::after(self.timeout), &key(index), &key(index - 1), quota.window)
+            .await?;
+        let elapsed = Duration::from_millis((now.as_millis() % window) as u64);
+
+        Ok(Decision::new(quota, Hits { current, previous, elapsed }))
+    }
+}
+
+/// Identifies the client a request is counted against. Only identities the
+/// auth layers verified are used, a client can't pick its own.
+fn client_key(extensions: &http::Extensions) -> String {
+    if let Some(claims) = extensions.get::<Claims>() {
+        if let Some(id) = claims.get("api_key_id").and_then(|id| id.as_str()) {
+            return format!("key:{}", id);
+        }
+        if let Some(subject) = claims.subject() {
+            return format!("sub:{}", subject);
+        }
+    }
+    if let Some(identity) = extensions.get::<ClientIdentity>() {
+        return format!("cert:{}", identity.subject);
+    }
+
+    ip_key(extensions)
+}
+
+fn ip_key(extensions: &http::Extensions) -> String {
+    let remote_addr = extensions
+        .get::<TcpConnectInfo>()
+        .or_else(|| extensions.get::<TlsConnectInfo<TcpConnectInfo>>().map(|info| info.get_ref()))
+        .and_then(|info| info.remote_addr());
+    match remote_addr {
+        Some(addr) => format!("ip:{}", addr.ip()),
+        None => "anonymous".to_string(),
+    }
+}
+
+/// Counts requests against their method's [`RateLimits`] and rejects those
+/// over quota with `RESOURCE_EXHAUSTED`. Responses carry `x-ratelimit-limit`,
+/// `x-ratelimit-remaining` and `x-ratelimit-reset` (seconds) metadata.
+#[derive(Clone, Default)]
+pub struct RateLimitLayer {
+    limits: RateLimits,
+    per_ip: bool,
+}
+
+impl RateLimitLayer {
+    /// Counts authenticated clients against the quota of each method. Goes
+    /// after the auth layers, which tell clients apart.
+    pub fn new(limits: RateLimits) -> Self {
+        RateLimitLayer { limits, per_ip: false }
+    }
+
+    /// Counts every request of an IP address against the per address quota.
+    /// Goes before the auth layers, so that failed attempts count too.
+    pub fn per_ip(limits: RateLimits) -> Self {
+        RateLimitLayer { limits, per_ip: true }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitService {
+            inner,
+            limits: self.limits.clone(),
+            per_ip: self.per_ip,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RateLimitService<S> {
+    inner: S,
+    limits: RateLimits,
+    per_ip: bool,
+}
+
+impl<S, B> Service<http::Request<B>> for RateLimitService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    B: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let (method, quota) = match self.per_ip {
+            true => (ANY_METHOD.to_string(), self.limits.per_ip),
+            false => {
+                let method = request.uri().path().to_string();
+                let quota = self.limits.quota(&method);
+                (method, quota)
+            }
+        };
+        let (quota, cache) = match (quota, &self.limits.cache) {
+            (Some(quota), Some(cache)) => (quota, cache.clone()),
+            _ => return self.inner.call(request).boxed(),
+        };
+
+        // The inner service was polled ready, keep it and leave a fresh clone in its place
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let limits = self.limits.clone();
+        let per_ip = self.per_ip;
+
+        async move {
+            let client = match per_ip {
+                true => ip_key(request.extensions()),
+                false => client_key(request.extensions()),
+            };
+            let decision = limits.check(&cache, &method, quota, &client).await;
+            let record = |decision| {
+                metrics()
+                    .rate_limit_decisions
+                    .get_or_create(&RateLimitLabels { method: method.clone(), decision })
+                    .inc();
+            };
+
+            let decision = match decision {
+                Ok(decision) if decision.allowed => {
+                    record("allowed");
+                    decision
+                }
+                Ok(decision) => {
+                    record("limited");
+                    warn!("Rate limited {} calling {}", client, method);
+
+                    let message = match per_ip {
+                        true => "rate limit of this address exceeded".to_string(),
+                        false => format!("rate limit of {} exceeded", method),
+                    };
+                    let mut status = tonic::Status::from(ApiError::ResourceExhausted(message));
+                    decision.insert_metadata(status.metadata_mut());
+                    status.metadata_mut().insert(RETRY_PUSHBACK, MetadataValue::from(decision.reset.as_millis() as u64));
+                    return Ok(status.to_http());
+                }
+                Err(_) if limits.fail_open => {
+                    record("error");
+                    return inner.call(request).await;
+                }
+                Err(e) => {
+                    record("error");
+                    return Ok(tonic::Status::from(e).to_http());
+                }
+            };
+
+            let mut response = inner.call(request).await?;
+            let mut metadata = MetadataMap::new();
+            decision.insert_metadata(&mut metadata);
+            response.headers_mut().extend(metadata.into_headers());
+            Ok(response)
+        }.boxed()
+    }
+}
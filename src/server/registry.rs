@@ -0,0 +1,215 @@
+use std::convert::Infallible;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use pbjson_types::FileDescriptorSet;
+use prost::Message;
+use tonic::body::BoxBody;
+use tonic::codegen::http;
+use tonic::server::NamedService;
+use tonic::transport::server::Routes;
+use tonic::transport::Body;
+use tonic_health::server::HealthReporter;
+use tower::{Layer, Service};
+use crate::database::{CacheClient, PgPool};
+use crate::gateway::RestGateway;
+use crate::server::services::SERVICES;
+
+/// Dependencies the services are built from.
+#[derive(Clone)]
+pub struct AppState {
+    pub pool: Arc<PgPool>,
+    pub cache: CacheClient,
+}
+
+/// Everything the server needs to know about a gRPC service. Implement it
+/// next to the service and list it in [`SERVICES`].
+pub trait ServiceModule: Sync {
+    /// Encoded file descriptor set of the service's protos, served by reflection.
+    fn descriptor_set(&self) -> &'static [u8];
+
+    /// Adds the tonic server, wrapped in its own middleware if any.
+    fn register(&self, registry: &mut ServiceRegistry, state: &AppState);
+
+    /// Registers the message types of the RPCs exposed on the REST gateway.
+    fn rest(&self, gateway: RestGateway) -> RestGateway {
+        gateway
+    }
+}
+
+/// Collects the routes of the services and the names their health is reported under.
+#[derive(Default)]
+pub struct ServiceRegistry {
+    routes: Routes,
+    health_names: Vec<&'static str>,
+}
+
+impl ServiceRegistry {
+    pub fn add_service<S>(&mut self, service: S) -> &mut Self
+    where
+        S: Service<http::Request<Body>, Response = http::Response<BoxBody>, Error = Infallible>
+            + NamedService
+            + Clone
+            + Send
+            + 'static,
+        S::Future: Send + 'static,
+    {
+        self.health_names.push(S::NAME);
+        self.routes = std::mem::take(&mut self.routes).add_service(service);
+        self
+    }
+
+    /// Adds `service` wrapped in `layer`, which only applies to this service.
+    pub fn add_layered_service<S, L>(&mut self, service: S, layer: L) -> &mut Self
+    where
+        S: NamedService + Send + 'static,
+        L: Layer<S>,
+        L::Service: Service<http::Request<Body>, Response = http::Response<BoxBody>, Error = Infallible>
+            + Clone
+            + Send
+            + 'static,
+        <L::Service as Service<http::Request<Body>>>::Future: Send + 'static,
+    {
+        self.add_service(Named::<_, S>::new(layer.layer(service)))
+    }
+}
+
+/// The router built from the service modules.
+pub struct App {
+    /// Every service, plus reflection and health.
+    pub routes: Routes,
+    pub health: HealthReporter,
+    /// Services whose status follows the dependency checks.
+    pub health_names: Vec<&'static str>,
+}
+
+/// Builds the same [`App`] for production and the integration tests from a
+/// list of service modules.
+#[derive(Clone)]
+pub struct AppBuilder {
+    modules: Vec<&'static dyn ServiceModule>,
+}
+
+impl Default for AppBuilder {
+    fn default() -> Self {
+        AppBuilder::new()
+    }
+}
+
+impl AppBuilder {
+    /// Every service in [`SERVICES`].
+    pub fn new() -> Self {
+        AppBuilder { modules: SERVICES.to_vec() }
+    }
+
+    /// No service besides reflection and health.
+    pub fn empty() -> Self {
+        AppBuilder { modules: Vec::new() }
+    }
+
+    pub fn module(mut self, module: &'static dyn ServiceModule) -> Self {
+        self.modules.push(module);
+        self
+    }
+
+    /// Descriptor sets of the services and of the health service.
+    pub fn descriptor_sets(&self) -> Vec<&'static [u8]> {
+        self.modules
+            .iter()
+            .map(|module| module.descriptor_set())
+            .chain(std::iter::once(tonic_health::pb::FILE_DESCRIPTOR_SET))
+            .collect()
+    }
+
+    /// gRPC paths, such as `/echo.v1.EchoService/UnaryEcho`, of the services'
+    /// methods. Reflection and health aren't included.
+    pub fn methods(&self) -> Result<Vec<String>, prost::DecodeError> {
+        let mut methods = Vec::new();
+        for module in &self.modules {
+            for file in FileDescriptorSet::decode(module.descriptor_set())?.file {
+                let package = file.package.as_deref().unwrap_or_default();
+                for service in &file.service {
+                    let service_name = service.name.as_deref().unwrap_or_default();
+                    let service_name = match package {
+                        "" => service_name.to_string(),
+                        package => format!("{}.{}", package, service_name),
+                    };
+                    for method in &service.method {
+                        methods.push(format!("/{}/{}", service_name, method.name.as_deref().unwrap_or_default()));
+                    }
+                }
+            }
+        }
+
+        Ok(methods)
+    }
+
+    pub fn rest_gateway(&self) -> Result<RestGateway, prost::DecodeError> {
+        let descriptor_sets = self.modules.iter().map(|module| module.descriptor_set()).collect::<Vec<_>>();
+        let gateway = RestGateway::new(&descriptor_sets)?;
+        Ok(self.modules.iter().fold(gateway, |gateway, module| module.rest(gateway)))
+    }
+
+    pub fn build(&self, state: &AppState) -> App {
+        let mut registry = ServiceRegistry::default();
+        for module in &self.modules {
+            module.register(&mut registry, state);
+        }
+
+        let reflect = self
+            .descriptor_sets()
+            .into_iter()
+            .fold(tonic_reflection::server::Builder::configure(), |builder, descriptor_set| {
+                builder.register_encoded_file_descriptor_set(descriptor_set)
+            })
+            .build()
+            .unwrap();
+        let (health, health_service) = tonic_health::server::health_reporter();
+
+        App {
+            routes: registry.routes.add_service(reflect).add_service(health_service),
+            health,
+            health_names: registry.health_names,
+        }
+    }
+}
+
+/// Keeps the name of a service once a layer wrapped it, so it can still be routed.
+struct Named<T, S> {
+    inner: T,
+    _service: PhantomData<fn() -> S>,
+}
+
+impl<T, S> Named<T, S> {
+    fn new(inner: T) -> Self {
+        Named { inner, _service: PhantomData }
+    }
+}
+
+impl<T: Clone, S> Clone for Named<T, S> {
+    fn clone(&self) -> Self {
+        Named::new(self.inner.clone())
+    }
+}
+
+impl<T, S: NamedService> NamedService for Named<T, S> {
+    const NAME: &'static str = S::NAME;
+}
+
+impl<T, S, R> Service<R> for Named<T, S>
+where
+    T: Service<R>,
+{
+    type Response = T::Response;
+    type Error = T::Error;
+    type Future = T::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: R) -> Self::Future {
+        self.inner.call(request)
+    }
+}
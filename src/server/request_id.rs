@@ -0,0 +1,125 @@
+use std::fmt;
+use std::future::Future;
+use std::task::{Context, Poll};
+
+use futures_util::future::{BoxFuture, FutureExt};
+use tonic::body::BoxBody;
+use tonic::codegen::http::{self, HeaderValue};
+use tower::{Layer, Service};
+use tracing::{Instrument, Span};
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Longest client-supplied ID kept, longer ones are replaced.
+const MAX_LENGTH: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: RequestId;
+}
+
+/// ID of the request being handled, taken from `x-request-id` or generated.
+/// It is set for the whole handling of a request, so errors and log records
+/// can be matched with what the client saw.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(String);
+
+impl RequestId {
+    /// Keeps a client-supplied ID made of visible ASCII, generates one otherwise.
+    pub fn from_headers(headers: &http::HeaderMap) -> Self {
+        headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|id| !id.is_empty() && id.len() <= MAX_LENGTH && id.bytes().all(|b| b.is_ascii_graphic()))
+            .map(|id| RequestId(id.to_string()))
+            .unwrap_or_else(|| RequestId(Uuid::new_v4().to_string()))
+    }
+
+    pub fn from_request<T>(request: &tonic::Request<T>) -> Option<&Self> {
+        request.extensions().get::<RequestId>()
+    }
+
+    /// ID of the request handled by the current task.
+    pub fn current() -> Option<RequestId> {
+        REQUEST_ID.try_with(RequestId::clone).ok()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Runs `future` under the ID and span of the current request. Tasks
+    /// spawned while handling a request don't inherit them otherwise.
+    pub fn propagate<F: Future>(future: F) -> impl Future<Output = F::Output> {
+        let id = RequestId::current();
+        let span = Span::current();
+        async move {
+            match id {
+                Some(id) => REQUEST_ID.scope(id, future).await,
+                None => future.await,
+            }
+        }
+        .instrument(span)
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Gives every request a [`RequestId`]: it is put in the request extensions
+/// and headers, set for the rest of the stack and echoed in the response.
+#[derive(Debug, Clone, Default)]
+pub struct RequestIdLayer;
+
+impl RequestIdLayer {
+    pub fn new() -> Self {
+        RequestIdLayer
+    }
+}
+
+impl<S> Layer<S> for RequestIdLayer {
+    type Service = RequestIdService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestIdService { inner }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RequestIdService<S> {
+    inner: S,
+}
+
+impl<S, B> Service<http::Request<B>> for RequestIdService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<B>) -> Self::Future {
+        let id = RequestId::from_headers(request.headers());
+        let header = HeaderValue::from_str(id.as_str()).expect("request IDs are visible ASCII");
+        request.headers_mut().insert(REQUEST_ID_HEADER, header.clone());
+        request.extensions_mut().insert(id.clone());
+
+        // Layers below may reject the request synchronously in `call`
+        let future = REQUEST_ID.sync_scope(id.clone(), || self.inner.call(request));
+        REQUEST_ID
+            .scope(id, async move {
+                let mut response = future.await?;
+                response.headers_mut().insert(REQUEST_ID_HEADER, header);
+                Ok(response)
+            })
+            .boxed()
+    }
+}
@@ -1,93 +1,253 @@
-use std::env;
-use std::sync::Arc;
+use std::net::SocketAddr;
+use std::time::Duration;
 
-use ::log::{info, warn};
+use tracing::{info, warn};
 use tokio::task::JoinHandle;
-use tonic::transport::{Certificate, Identity, Server, ServerTlsConfig};
-use protos::echo::v1::echo_service_server::EchoServiceServer;
-use crate::database::{CacheClient, PgPool};
-use crate::{create_socket_addr, report_error};
-use crate::server::services::v1::echo::echo_service::EchoServiceServerImpl;
+use tonic::transport::Server;
+use tonic::transport::server::Routes;
+use tonic_health::server::HealthReporter;
+use tonic_web::GrpcWebLayer;
+use tower::util::option_layer;
+use tower::Layer;
+use crate::gateway::RestGateway;
+use crate::config::Config;
+use crate::report_error;
+use crate::server::{spawn_health_checker, AccessLog, AccessLogLayer, ApiKeyAuthenticator, ApiKeyLayer, AppBuilder, AppState, CatchPanicLayer, ClientAllowlist, ClientIdentityLayer, ConcurrencyLimitLayer, ConcurrencyLimits, GrpcWebConfig, JwtAuthLayer, JwtAuthenticator, ListenerConfig, Objectives, Policy, PolicyLayer, RateLimitLayer, RateLimits, RequestIdLayer, RpcSpanLayer, ShutdownTrigger, SloLayer, TlsAcceptor, TlsSettings};
+
+/// Layers wrapped around the routes of every listener.
+#[derive(Clone, Default)]
+pub struct Middleware {
+    pub grpc_web: Option<GrpcWebConfig>,
+    pub access_log: AccessLog,
+    pub objectives: Objectives,
+    pub allowlist: ClientAllowlist,
+    pub api_keys: ApiKeyAuthenticator,
+    pub auth: JwtAuthenticator,
+    pub policy: Policy,
+    pub limits: ConcurrencyLimits,
+    pub rate_limits: RateLimits,
+}
 
 pub struct TonicServer {
     pub handle: JoinHandle<()>,
     pub tls: bool,
+    pub shutdown: ShutdownTrigger,
+    pub health: HealthReporter,
+    pub routes: Routes,
+    pub middleware: Middleware,
+}
+
+impl TonicServer {
+    /// Stops accepting new connections and lets in-flight RPCs finish.
+    /// Await `handle` to know when draining is done.
+    pub fn shutdown(&self) {
+        self.shutdown.trigger();
+    }
 }
 
-pub fn start_server(
-    pool: Arc<PgPool>,
-    cache_client: CacheClient,
-    port: u16,
+pub async fn start_server(
+    config: &Config,
+    app: &AppBuilder,
+    state: AppState,
 ) -> Result<TonicServer, Box<dyn std::error::Error>> {
-    let echo = EchoServiceServerImpl::new(pool, cache_client);
-
-    let (mut tonic_server, secure_mode) = match get_tls_config() {
-        Some(tls) => {
-            info!("Configuring TLS...");
-            match Server::builder().tls_config(tls) {
-                Ok(server) => {
-                    info!("TLS successfully configured.");
-                    (server, true)
-                }
-                Err(details) => {
-                    info!("Error configuring TLS. Connections are not secure.");
-                    report_error(&details);
-                    (Server::builder(), false)
-                }
-            }
-        }
-        _ => {
-            warn!("No TLS keys available. Connections are not secure.");
-            (Server::builder(), false)
-        }
+    let tls_required = config.tls.required;
+    // gRPC-Web clients may only speak HTTP/1.1
+    let tls_settings = TlsSettings::from_options(&config.tls).map(|settings| TlsSettings { http1: config.grpc_web.enabled, ..settings });
+    let tls = configure_tls(tls_settings, tls_required)?;
+    let secure_mode = tls.is_some();
+
+    let listeners = ListenerConfig::from_options(&config.server)?;
+    if let Some(listener) = listeners.iter().find(|listener| tls_required && listener.tls == Some(false)) {
+        return Err(format!("TLS is required but listener {} disables TLS", listener).into());
+    }
+
+    let methods = app.methods()?;
+    let objectives = Objectives::new(&config.slo, &methods)?;
+    let limits = ConcurrencyLimits::new(&config.limits, &objectives, &methods)?;
+    let middleware = Middleware {
+        grpc_web: GrpcWebConfig::from_options(&config.grpc_web),
+        access_log: AccessLog::new(&config.access_log),
+        objectives,
+        allowlist: ClientAllowlist::parse(&config.tls.client_allowlist)?,
+        api_keys: match config.auth.api_keys {
+            true => ApiKeyAuthenticator::new(&state),
+            false => ApiKeyAuthenticator::default(),
+        },
+        auth: JwtAuthenticator::new(&config.auth).await?,
+        policy: match &config.policy.file {
+            Some(file) => Policy::load(file)?.dry_run(config.policy.dry_run),
+            None => Policy::default(),
+        },
+        limits,
+        rate_limits: RateLimits::new(&config.rate_limit, &methods, state.cache.clone())?,
     };
+    if config.auth.api_keys && config.rate_limit.per_ip_quota.is_empty() {
+        warn!("API keys are enabled without rate_limit.per_ip_quota, key guesses aren't rate limited");
+    }
 
-    let reflect = tonic_reflection::server::Builder::configure()
-        .register_encoded_file_descriptor_set(protos::echo::v1::FILE_DESCRIPTOR_SET)
-        .build()
-        .unwrap();
-
-    let tonic_router = tonic_server
-        .add_service(reflect)
-        .add_service(EchoServiceServer::new(echo));
-
-    let server = tokio::spawn(async move {
-        let tonic_addr = create_socket_addr(port);
-        info!("Starting server on port {}", port);
-        match tonic_router.serve(tonic_addr).await {
-            Ok(_) => info!("Server finished on {}", tonic_addr),
-            Err(e) => {
-                warn!("Unable to start server on port {}", port);
-                report_error(&e);
-            }
-        };
-        ()
-    });
+    let app = app.build(&state);
+
+    let shutdown = ShutdownTrigger::new();
+    if let Some(tls) = &tls {
+        tls.spawn_reloader(shutdown.clone());
+    }
+    middleware.auth.spawn_refresher(shutdown.clone());
+
+    spawn_health_checker(
+        app.health.clone(),
+        app.health_names,
+        state.pool,
+        state.cache,
+        shutdown.clone(),
+        Duration::from_secs(config.server.health_check_interval_secs),
+    );
+
+    let server = serve_listeners(app.routes.clone(), &listeners, tls, middleware.clone(), shutdown.clone())?;
 
     Ok(TonicServer {
         handle: server,
         tls: secure_mode,
+        shutdown,
+        health: app.health,
+        routes: app.routes,
+        middleware,
     })
 }
 
-fn get_tls_config() -> Option<ServerTlsConfig> {
-    let cert = env::var("TLS_CERT").ok();
-    let key = env::var("TLS_KEY").ok();
-    let ca_cert = env::var("CA_CERT").ok();
-
-    match (cert, key, ca_cert) {
-        (Some(cert), Some(key), Some(ca_cert)) => {
-            info!("Configuring TLS with custom CA...");
-            Some(
-                ServerTlsConfig::new()
-                    .identity(Identity::from_pem(cert, key))
-                    .client_ca_root(Certificate::from_pem(ca_cert)),
-            )
+/// Builds the TLS acceptor. Unless `required` is set, missing or invalid TLS
+/// material only logs a warning and the server runs in plaintext.
+pub fn configure_tls(
+    settings: Option<TlsSettings>,
+    required: bool,
+) -> Result<Option<TlsAcceptor>, Box<dyn std::error::Error>> {
+    let settings = match settings {
+        Some(settings) => settings,
+        None if required => return Err("TLS is required but no TLS certificate is configured".into()),
+        None => {
+            warn!("No TLS keys available. Connections are not secure.");
+            return Ok(None);
         }
-        (Some(cert), Some(key), None) => {
-            info!("Configuring TLS with official CAs...");
-            Some(ServerTlsConfig::new().identity(Identity::from_pem(cert, key)))
+    };
+
+    info!("Configuring TLS...");
+    match TlsAcceptor::new(settings) {
+        Ok(acceptor) => {
+            info!("TLS successfully configured.");
+            Ok(Some(acceptor))
         }
-        _ => None,
+        Err(details) if required => Err(format!("TLS is required but could not be configured: {}", details).into()),
+        Err(details) => {
+            info!("Error configuring TLS. Connections are not secure.");
+            report_error(details.as_ref());
+            Ok(None)
+        }
+    }
+}
+
+/// Serves `routes` on every listener until `shutdown` is triggered. Listeners
+/// use `tls` when it is set unless they opt out with `tls=false`. All listeners
+/// are bound before returning, so a bad address fails startup.
+pub fn serve_listeners(
+    routes: Routes,
+    listeners: &[ListenerConfig],
+    tls: Option<TlsAcceptor>,
+    middleware: Middleware,
+    shutdown: ShutdownTrigger,
+) -> Result<JoinHandle<()>, Box<dyn std::error::Error>> {
+    let Middleware { grpc_web, access_log, objectives, allowlist, api_keys, auth, policy, limits, rate_limits } = middleware;
+    let cors = match &grpc_web {
+        Some(config) => Some(config.cors_layer()?),
+        None => None,
+    };
+
+    let mut servers = Vec::with_capacity(listeners.len());
+    for listener in listeners {
+        let tls = match (listener.tls, &tls) {
+            (Some(false), _) | (None, None) => {
+                warn!("Serving {} without TLS", listener);
+                None
+            }
+            (_, Some(tls)) => Some(tls.clone()),
+            (Some(true), None) => return Err(format!("Listener {} requires TLS but TLS is not configured", listener).into()),
+        };
+
+        // gRPC-Web clients speak HTTP/1.1, CORS must wrap the translation layer
+        let router = Server::builder()
+            .accept_http1(grpc_web.is_some())
+            .layer(option_layer(cors.clone()))
+            .layer(option_layer(grpc_web.as_ref().map(|_| GrpcWebLayer::new())))
+            .layer(RequestIdLayer::new())
+            .layer(RpcSpanLayer::new())
+            .layer(AccessLogLayer::new(access_log.clone()))
+            .layer(SloLayer::new(objectives.clone()))
+            .layer(CatchPanicLayer::new())
+            .layer(RateLimitLayer::per_ip(rate_limits.clone()))
+            .layer(ClientIdentityLayer::new(allowlist.clone()))
+            .layer(ApiKeyLayer::new(api_keys.clone()))
+            .layer(JwtAuthLayer::new(auth.clone()))
+            .layer(PolicyLayer::new(policy.clone()))
+            .layer(RateLimitLayer::new(rate_limits.clone()))
+            .layer(ConcurrencyLimitLayer::new(limits.clone()))
+            .add_routes(routes.clone());
+
+        let incoming = listener.bind()?;
+        let listener = listener.clone();
+        let signal = shutdown.clone();
+        servers.push(async move {
+            let result = match tls {
+                Some(tls) => router.serve_with_incoming_shutdown(tls.accept(incoming), signal.triggered()).await,
+                None => router.serve_with_incoming_shutdown(incoming, signal.triggered()).await,
+            };
+            match result {
+                Ok(_) => info!("Server finished on {}", listener),
+                Err(e) => {
+                    warn!("Server on {} failed", listener);
+                    report_error(&e);
+                }
+            };
+            listener.cleanup();
+        });
     }
+
+    Ok(tokio::spawn(async move {
+        futures_util::future::join_all(servers).await;
+    }))
+}
+
+/// Serves the REST/JSON gateway for every RPC with a `google.api.http` option.
+/// Requests carry no client certificate, so methods restricted by
+/// the client allowlist are rejected. Concurrency and rate limits are shared
+/// with the gRPC listeners. The gateway serves plaintext, the config refuses
+/// it when `tls.required` is set.
+pub async fn start_rest_gateway(
+    gateway: RestGateway,
+    routes: Routes,
+    middleware: Middleware,
+    addr: SocketAddr,
+    shutdown: ShutdownTrigger,
+) -> Result<JoinHandle<()>, Box<dyn std::error::Error>> {
+    let routes = ConcurrencyLimitLayer::new(middleware.limits).layer(routes);
+    let routes = RateLimitLayer::new(middleware.rate_limits.clone()).layer(routes);
+    let routes = PolicyLayer::new(middleware.policy).layer(routes);
+    let routes = JwtAuthLayer::new(middleware.auth).layer(routes);
+    let routes = ApiKeyLayer::new(middleware.api_keys).layer(routes);
+    let routes = ClientIdentityLayer::new(middleware.allowlist).layer(routes);
+    let routes = RateLimitLayer::per_ip(middleware.rate_limits).layer(routes);
+    let routes = CatchPanicLayer::new().layer(routes);
+    let routes = SloLayer::new(middleware.objectives).layer(routes);
+    let routes = AccessLogLayer::new(middleware.access_log).layer(routes);
+    let routes = RpcSpanLayer::new().layer(routes);
+    let app = gateway.into_router(RequestIdLayer::new().layer(routes));
+
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    info!("REST gateway listening on {}", addr);
+
+    Ok(tokio::spawn(async move {
+        if let Err(e) = axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+            .with_graceful_shutdown(async move { shutdown.triggered().await })
+            .await
+        {
+            report_error(&e);
+        }
+    }))
 }
@@ -1 +1,8 @@
-pub mod v1;
\ No newline at end of file
+use crate::server::ServiceModule;
+use crate::server::services::v1::apikey::apikey_module::ApiKeyModule;
+use crate::server::services::v1::echo::echo_module::EchoModule;
+
+pub mod v1;
+
+/// Every service the server exposes.
+pub const SERVICES: &[&dyn ServiceModule] = &[&EchoModule, &ApiKeyModule];
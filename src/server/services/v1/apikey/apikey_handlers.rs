@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use pbjson_types::Timestamp;
+use protos::apikey::v1::{
+    ApiKey, CreateApiKeyRequest, CreateApiKeyResponse, ListApiKeysRequest, ListApiKeysResponse, RevokeApiKeyRequest,
+    RevokeApiKeyResponse,
+};
+use tracing::warn;
+use uuid::Uuid;
+use crate::database::{self, ApiKeyRecord, CacheClient, PgPooledConnection};
+use crate::errors::ApiError;
+use crate::server::ApiKeyAuthenticator;
+use crate::utils::deadline::Deadline;
+use crate::utils::validation::ValidateRequest;
+
+fn timestamp(time: DateTime<Utc>) -> Timestamp {
+    Timestamp {
+        seconds: time.timestamp(),
+        nanos: time.timestamp_subsec_nanos() as i32,
+    }
+}
+
+impl From<ApiKeyRecord> for ApiKey {
+    fn from(record: ApiKeyRecord) -> Self {
+        ApiKey {
+            id: record.id.to_string(),
+            prefix: record.prefix,
+            owner: record.owner,
+            scopes: record.scopes,
+            expires_at: record.expires_at.map(timestamp),
+            last_used_at: record.last_used_at.map(timestamp),
+            created_at: Some(timestamp(record.created_at)),
+            revoked_at: record.revoked_at.map(timestamp),
+        }
+    }
+}
+
+pub async fn create_api_key(
+    request: CreateApiKeyRequest,
+    conn: &mut PgPooledConnection,
+) -> Result<CreateApiKeyResponse, ApiError> {
+    request.validate()?;
+
+    let expires_at = request
+        .expires_at
+        .map(|expires_at| DateTime::from_timestamp(expires_at.seconds, expires_at.nanos.max(0) as u32))
+        .map(|expires_at| expires_at.ok_or_else(|| ApiError::InvalidRequest("expires_at is out of range".to_string())))
+        .transpose()?;
+    let (record, secret) = database::insert_api_key(conn, &request.owner, &request.scopes, expires_at).await?;
+
+    Ok(CreateApiKeyResponse {
+        api_key: Some(record.into()),
+        secret,
+    })
+}
+
+pub async fn list_api_keys(
+    request: ListApiKeysRequest,
+    conn: &mut PgPooledConnection,
+) -> Result<ListApiKeysResponse, ApiError> {
+    let owner = Some(request.owner.as_str()).filter(|owner| !owner.is_empty());
+    let records = database::list_api_keys(conn, owner, request.include_revoked).await?;
+
+    Ok(ListApiKeysResponse {
+        api_keys: records.into_iter().map(ApiKey::from).collect(),
+    })
+}
+
+pub async fn revoke_api_key(
+    request: RevokeApiKeyRequest,
+    conn: &mut PgPooledConnection,
+    cache: &CacheClient,
+    deadline: Deadline,
+) -> Result<RevokeApiKeyResponse, ApiError> {
+    request.validate()?;
+
+    let id = Uuid::parse_str(&request.id).map_err(|e| ApiError::ParsingError(e.to_string()))?;
+    let record = database::get_api_key(conn, id).await?;
+
+    // Before, so lookups from now on hit the database, and after, in case one
+    // cached the record while it was being revoked. The revocation is stored
+    // either way, a stale entry only lives until the cache TTL.
+    if let Err(e) = ApiKeyAuthenticator::invalidate(cache, deadline, &record.key_hash).await {
+        warn!("Couldn't clear the cached API key {} before revoking it: {}", id, e);
+    }
+    let record = database::revoke_api_key(conn, id).await?;
+    if let Err(e) = ApiKeyAuthenticator::invalidate(cache, deadline, &record.key_hash).await {
+        warn!("Couldn't clear the cached API key {} after revoking it: {}", id, e);
+    }
+
+    Ok(RevokeApiKeyResponse {
+        api_key: Some(record.into()),
+    })
+}
@@ -0,0 +1,16 @@
+use protos::apikey::v1::api_key_service_server::ApiKeyServiceServer;
+use crate::server::services::v1::apikey::apikey_service::ApiKeyServiceServerImpl;
+use crate::server::{AppState, ServiceModule, ServiceRegistry};
+
+pub struct ApiKeyModule;
+
+impl ServiceModule for ApiKeyModule {
+    fn descriptor_set(&self) -> &'static [u8] {
+        protos::apikey::v1::FILE_DESCRIPTOR_SET
+    }
+
+    fn register(&self, registry: &mut ServiceRegistry, state: &AppState) {
+        let api_keys = ApiKeyServiceServerImpl::new(state.pool.clone(), state.cache.clone());
+        registry.add_service(ApiKeyServiceServer::new(api_keys));
+    }
+}
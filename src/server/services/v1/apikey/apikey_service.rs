@@ -0,0 +1,97 @@
+use std::sync::Arc;
+use autometrics::autometrics;
+use tonic::{Request, Response, Status};
+
+use crate::database::{CacheClient, get_connection, PgPool};
+
+use autometrics::objectives::{
+    Objective, ObjectiveLatency, ObjectivePercentile
+};
+use protos::apikey::v1::api_key_service_server::ApiKeyService;
+use protos::apikey::v1::{
+    CreateApiKeyRequest, CreateApiKeyResponse, ListApiKeysRequest, ListApiKeysResponse, RevokeApiKeyRequest,
+    RevokeApiKeyResponse,
+};
+use crate::errors::ApiError;
+use crate::server::Claims;
+use crate::server::services::v1::apikey::apikey_handlers::{create_api_key, list_api_keys, revoke_api_key};
+use crate::utils::deadline::Deadline;
+
+const API_SLO: Objective = Objective::new("api")
+    .success_rate(ObjectivePercentile::P99_9)
+    .latency(ObjectiveLatency::Ms250, ObjectivePercentile::P99);
+
+/// Scope a JWT or API key needs to manage API keys.
+pub const ADMIN_SCOPE: &str = "api_keys:admin";
+
+pub struct ApiKeyServiceServerImpl {
+    pub pool: Arc<PgPool>,
+    pub cache: CacheClient,
+}
+
+impl Clone for ApiKeyServiceServerImpl {
+    fn clone(&self) -> Self {
+        ApiKeyServiceServerImpl {
+            pool: Arc::clone(&self.pool),
+            cache: self.cache.clone(),
+        }
+    }
+}
+
+impl ApiKeyServiceServerImpl {
+    pub(crate) fn new(pool: Arc<PgPool>, cache: CacheClient) -> Self {
+        ApiKeyServiceServerImpl {
+            pool,
+            cache,
+        }
+    }
+}
+
+/// Checked here rather than by a rule of the default [`Policy`](crate::server::Policy):
+/// a policy file replaces the default rules and `policy.dry_run` lets denied
+/// requests through, and neither may open up API key management.
+fn require_admin<T>(request: &Request<T>) -> Result<(), ApiError> {
+    let claims = Claims::from_request(request)
+        .ok_or_else(|| ApiError::Unauthenticated("managing API keys requires credentials".to_string()))?;
+
+    if claims.scopes().contains(&ADMIN_SCOPE) {
+        Ok(())
+    } else {
+        Err(ApiError::PermissionDenied(format!("managing API keys requires the {} scope", ADMIN_SCOPE)))
+    }
+}
+
+#[tonic::async_trait]
+#[autometrics(objective = API_SLO)]
+impl ApiKeyService for ApiKeyServiceServerImpl {
+    async fn create_api_key(&self, request: Request<CreateApiKeyRequest>) -> Result<Response<CreateApiKeyResponse>, Status> {
+        require_admin(&request)?;
+        let mut conn = get_connection(&self.pool, Deadline::from_request(&request)).await?;
+
+        create_api_key(request.into_inner(), &mut conn)
+            .await
+            .map(Response::new)
+            .map_err(|e| e.into())
+    }
+
+    async fn list_api_keys(&self, request: Request<ListApiKeysRequest>) -> Result<Response<ListApiKeysResponse>, Status> {
+        require_admin(&request)?;
+        let mut conn = get_connection(&self.pool, Deadline::from_request(&request)).await?;
+
+        list_api_keys(request.into_inner(), &mut conn)
+            .await
+            .map(Response::new)
+            .map_err(|e| e.into())
+    }
+
+    async fn revoke_api_key(&self, request: Request<RevokeApiKeyRequest>) -> Result<Response<RevokeApiKeyResponse>, Status> {
+        require_admin(&request)?;
+        let deadline = Deadline::from_request(&request);
+        let mut conn = get_connection(&self.pool, deadline).await?;
+
+        revoke_api_key(request.into_inner(), &mut conn, &self.cache, deadline)
+            .await
+            .map(Response::new)
+            .map_err(|e| e.into())
+    }
+}
@@ -0,0 +1,44 @@
+use chrono::Utc;
+use protos::apikey::v1::{CreateApiKeyRequest, RevokeApiKeyRequest};
+use uuid::Uuid;
+use crate::errors::{ApiError, List, ValidationErrorKind, ValidationErrorMessage};
+use crate::errors::ApiError::ValidationError;
+use crate::utils::validation::ValidateRequest;
+
+pub const MAX_OWNER_LENGTH: usize = 255;
+
+impl ValidateRequest for CreateApiKeyRequest {
+    fn validate(&self) -> Result<(), ApiError> {
+        let mut errors = Vec::new();
+
+        if self.owner.is_empty() {
+            errors.push(ValidationErrorKind::MissingField("owner".to_string()));
+        } else if self.owner.len() > MAX_OWNER_LENGTH {
+            errors.push(ValidationErrorKind::InvalidLength("owner".to_string(), 1, MAX_OWNER_LENGTH));
+        }
+        if self.scopes.iter().any(|scope| scope.trim().is_empty()) {
+            errors.push(ValidationErrorKind::InvalidFormat("scopes".to_string(), ValidationErrorMessage::BlankEntry));
+        }
+        if self.expires_at.as_ref().is_some_and(|expires_at| expires_at.seconds <= Utc::now().timestamp()) {
+            errors.push(ValidationErrorKind::InvalidFormat("expires_at".to_string(), ValidationErrorMessage::NotInFuture));
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError(List(errors)))
+        }
+    }
+}
+
+impl ValidateRequest for RevokeApiKeyRequest {
+    fn validate(&self) -> Result<(), ApiError> {
+        if Uuid::parse_str(&self.id).is_err() {
+            return Err(ValidationError(List(vec![
+                ValidationErrorKind::InvalidFormat("id".to_string(), ValidationErrorMessage::NotUuid),
+            ])));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+pub mod apikey_module;
+pub mod apikey_service;
+mod apikey_handlers;
+mod apikey_validation;
\ No newline at end of file
@@ -1,8 +1,15 @@
-use protos::echo::v1::{UnaryEchoRequest, UnaryEchoResponse};
+use protos::echo::v1::{
+    BidirectionalStreamingEchoRequest, BidirectionalStreamingEchoResponse, ClientStreamingEchoRequest,
+    ClientStreamingEchoResponse, ServerStreamingEchoRequest, ServerStreamingEchoResponse, UnaryEchoRequest,
+    UnaryEchoResponse,
+};
 use crate::database::PgPooledConnection;
-use crate::errors::ApiError;
+use crate::errors::{ApiError, List, ValidationErrorKind};
 use crate::utils::validation::ValidateRequest;
 
+/// Most messages a client stream may send.
+pub const MAX_CLIENT_STREAM_MESSAGES: usize = 1000;
+
 pub async fn echo(
     request: UnaryEchoRequest,
     _conn: &mut PgPooledConnection,
@@ -12,4 +19,48 @@ pub async fn echo(
     Ok(UnaryEchoResponse {
         message: request.message,
     })
-}
\ No newline at end of file
+}
+
+/// The responses of the stream, in order.
+pub fn server_streaming_echo(
+    request: &ServerStreamingEchoRequest,
+) -> Result<Vec<ServerStreamingEchoResponse>, ApiError> {
+    request.validate()?;
+
+    Ok((0..request.count)
+        .map(|sequence| ServerStreamingEchoResponse {
+            message: request.message.clone(),
+            sequence,
+        })
+        .collect())
+}
+
+pub fn client_streaming_echo(
+    requests: Vec<ClientStreamingEchoRequest>,
+) -> Result<ClientStreamingEchoResponse, ApiError> {
+    if requests.is_empty() || requests.len() > MAX_CLIENT_STREAM_MESSAGES {
+        return Err(ApiError::ValidationError(List(vec![ValidationErrorKind::InvalidRange(
+            "messages".to_string(),
+            1,
+            MAX_CLIENT_STREAM_MESSAGES,
+        )])));
+    }
+
+    let mut messages = Vec::with_capacity(requests.len());
+    for request in requests {
+        request.validate()?;
+        messages.push(request.message);
+    }
+
+    Ok(ClientStreamingEchoResponse { messages })
+}
+
+pub fn bidirectional_streaming_echo(
+    request: BidirectionalStreamingEchoRequest,
+) -> Result<BidirectionalStreamingEchoResponse, ApiError> {
+    request.validate()?;
+
+    Ok(BidirectionalStreamingEchoResponse {
+        message: request.message,
+    })
+}
@@ -0,0 +1,22 @@
+use protos::echo::v1::echo_service_server::EchoServiceServer;
+use protos::echo::v1::{UnaryEchoRequest, UnaryEchoResponse};
+use crate::gateway::RestGateway;
+use crate::server::services::v1::echo::echo_service::EchoServiceServerImpl;
+use crate::server::{AppState, ServiceModule, ServiceRegistry};
+
+pub struct EchoModule;
+
+impl ServiceModule for EchoModule {
+    fn descriptor_set(&self) -> &'static [u8] {
+        protos::echo::v1::FILE_DESCRIPTOR_SET
+    }
+
+    fn register(&self, registry: &mut ServiceRegistry, state: &AppState) {
+        let echo = EchoServiceServerImpl::new(state.pool.clone(), state.cache.clone());
+        registry.add_service(EchoServiceServer::new(echo));
+    }
+
+    fn rest(&self, gateway: RestGateway) -> RestGateway {
+        gateway.register::<UnaryEchoRequest, UnaryEchoResponse>("/echo.v1.EchoService/UnaryEcho")
+    }
+}
@@ -1,6 +1,12 @@
+use std::pin::Pin;
 use std::sync::{Arc};
+use std::time::Duration;
+use tracing::debug;
 use autometrics::autometrics;
-use tonic::{Request, Response, Status};
+use futures_util::{future, Stream, StreamExt};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status, Streaming};
 
 use crate::database::{CacheClient, get_connection, PgPool};
 
@@ -8,13 +14,28 @@ use autometrics::objectives::{
     Objective, ObjectiveLatency, ObjectivePercentile
 };
 use protos::echo::v1::echo_service_server::EchoService;
-use protos::echo::v1::{UnaryEchoRequest, UnaryEchoResponse};
-use crate::server::services::v1::echo::echo_handlers::echo;
+use protos::echo::v1::{
+    BidirectionalStreamingEchoRequest, BidirectionalStreamingEchoResponse, ClientStreamingEchoRequest,
+    ClientStreamingEchoResponse, ServerStreamingEchoRequest, ServerStreamingEchoResponse, UnaryEchoRequest,
+    UnaryEchoResponse,
+};
+use crate::metrics::metrics;
+use crate::utils::deadline::Deadline;
+use crate::server::RequestId;
+use crate::server::services::v1::echo::echo_handlers::{
+    bidirectional_streaming_echo, client_streaming_echo, echo, server_streaming_echo, MAX_CLIENT_STREAM_MESSAGES,
+};
 
 const API_SLO: Objective = Objective::new("api")
     .success_rate(ObjectivePercentile::P99_9)
     .latency(ObjectiveLatency::Ms250, ObjectivePercentile::P99);
 
+/// Responses buffered per server stream. Once full, the stream waits for the
+/// client to read, so a slow client slows the stream down instead of growing memory.
+const STREAM_BUFFER: usize = 16;
+
+type BidirectionalStream = Pin<Box<dyn Stream<Item = Result<BidirectionalStreamingEchoResponse, Status>> + Send>>;
+
 pub struct EchoServiceServerImpl {
     pub pool: Arc<PgPool>,
     pub cache: CacheClient,
@@ -42,10 +63,11 @@ impl EchoServiceServerImpl {
 #[autometrics(objective = API_SLO)]
 impl EchoService for EchoServiceServerImpl {
     async fn unary_echo(&self, request: Request<UnaryEchoRequest>) -> Result<Response<UnaryEchoResponse>, Status> {
-        let mut conn = get_connection(&self.pool).await?;
+        let deadline = Deadline::from_request(&request);
+        let mut conn = get_connection(&self.pool, deadline).await?;
         let inner_request = request.into_inner();
 
-        self.cache.handle_cache("unary_echo", &inner_request.clone(), || {
+        self.cache.handle_cache(deadline, "unary_echo", &inner_request.clone(), || {
             async move {
                 echo(inner_request, &mut conn)
                     .await
@@ -54,4 +76,95 @@ impl EchoService for EchoServiceServerImpl {
             }
         }).await
     }
-}
\ No newline at end of file
+
+    type ServerStreamingEchoStream = ReceiverStream<Result<ServerStreamingEchoResponse, Status>>;
+
+    async fn server_streaming_echo(
+        &self,
+        request: Request<ServerStreamingEchoRequest>,
+    ) -> Result<Response<Self::ServerStreamingEchoStream>, Status> {
+        let request = request.into_inner();
+        let responses = server_streaming_echo(&request)?;
+        let interval = Duration::from_millis(request.interval_ms.into());
+
+        let (tx, rx) = mpsc::channel(STREAM_BUFFER);
+        tokio::spawn(RequestId::propagate(async move {
+            for response in responses {
+                if response.sequence > 0 && !interval.is_zero() {
+                    tokio::select! {
+                        _ = tokio::time::sleep(interval) => {}
+                        // The client cancelled or the server is shutting down
+                        _ = tx.closed() => break,
+                    }
+                }
+
+                if tx.send(Ok(response)).await.is_err() {
+                    break;
+                }
+                metrics().stream_message("ServerStreamingEcho", "sent");
+            }
+
+            if tx.is_closed() {
+                debug!("ServerStreamingEcho cancelled by the client");
+            }
+        }));
+
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+
+    async fn client_streaming_echo(
+        &self,
+        request: Request<Streaming<ClientStreamingEchoRequest>>,
+    ) -> Result<Response<ClientStreamingEchoResponse>, Status> {
+        let mut stream = request.into_inner();
+        let mut requests = Vec::new();
+
+        // Stop reading one message past the limit, the handler rejects the stream
+        while requests.len() <= MAX_CLIENT_STREAM_MESSAGES {
+            match stream.message().await? {
+                Some(request) => {
+                    metrics().stream_message("ClientStreamingEcho", "received");
+                    requests.push(request);
+                }
+                None => break,
+            }
+        }
+
+        client_streaming_echo(requests)
+            .map(Response::new)
+            .map_err(|e| e.into())
+    }
+
+    type BidirectionalStreamingEchoStream = BidirectionalStream;
+
+    async fn bidirectional_streaming_echo(
+        &self,
+        request: Request<Streaming<BidirectionalStreamingEchoRequest>>,
+    ) -> Result<Response<Self::BidirectionalStreamingEchoStream>, Status> {
+        // Each message is answered as it is read, so the client's pace drives the
+        // stream and dropping the response stream on cancellation stops reading
+        let responses = request
+            .into_inner()
+            .map(bidirectional_streaming_message)
+            // An invalid message ends the stream with its error, after the earlier responses
+            .scan(false, |failed, response| {
+                if *failed {
+                    return future::ready(None);
+                }
+                *failed = response.is_err();
+                future::ready(Some(response))
+            });
+
+        Ok(Response::new(Box::pin(responses)))
+    }
+}
+// tonic streams carry `Status` as their error, whatever its size
+#[allow(clippy::result_large_err)]
+fn bidirectional_streaming_message(
+    request: Result<BidirectionalStreamingEchoRequest, Status>,
+) -> Result<BidirectionalStreamingEchoResponse, Status> {
+    metrics().stream_message("BidirectionalStreamingEcho", "received");
+    let response = bidirectional_streaming_echo(request?)?;
+    metrics().stream_message("BidirectionalStreamingEcho", "sent");
+    Ok(response)
+}
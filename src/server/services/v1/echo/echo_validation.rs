@@ -1,14 +1,55 @@
-use protos::echo::v1::UnaryEchoRequest;
+use protos::echo::v1::{BidirectionalStreamingEchoRequest, ClientStreamingEchoRequest, ServerStreamingEchoRequest, UnaryEchoRequest};
 use crate::errors::{ApiError, List, ValidationErrorKind};
 use crate::errors::ApiError::ValidationError;
 use crate::utils::validation::ValidateRequest;
 
+pub const MAX_STREAM_COUNT: u32 = 100;
+pub const MAX_STREAM_INTERVAL_MS: u32 = 10_000;
+
+fn validate_message(message: &str) -> Result<(), ApiError> {
+    if message.is_empty() {
+        return Err(ValidationError(List(vec![ValidationErrorKind::MissingField("message".to_string())])));
+    }
+
+    Ok(())
+}
+
 impl ValidateRequest for UnaryEchoRequest {
     fn validate(&self) -> Result<(), ApiError> {
+        validate_message(&self.message)
+    }
+}
+
+impl ValidateRequest for ServerStreamingEchoRequest {
+    fn validate(&self) -> Result<(), ApiError> {
+        let mut errors = Vec::new();
+
         if self.message.is_empty() {
-            return Err(ValidationError(List(vec![ValidationErrorKind::MissingField("message".to_string())])));
+            errors.push(ValidationErrorKind::MissingField("message".to_string()));
+        }
+        if self.count == 0 || self.count > MAX_STREAM_COUNT {
+            errors.push(ValidationErrorKind::InvalidRange("count".to_string(), 1, MAX_STREAM_COUNT as usize));
         }
+        if self.interval_ms > MAX_STREAM_INTERVAL_MS {
+            errors.push(ValidationErrorKind::InvalidRange("interval_ms".to_string(), 0, MAX_STREAM_INTERVAL_MS as usize));
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError(List(errors)))
+        }
+    }
+}
 
-        Ok(())
+impl ValidateRequest for ClientStreamingEchoRequest {
+    fn validate(&self) -> Result<(), ApiError> {
+        validate_message(&self.message)
+    }
+}
+
+impl ValidateRequest for BidirectionalStreamingEchoRequest {
+    fn validate(&self) -> Result<(), ApiError> {
+        validate_message(&self.message)
     }
-}
\ No newline at end of file
+}
@@ -1,3 +1,4 @@
+pub mod echo_module;
 pub mod echo_service;
 mod echo_handlers;
 mod echo_validation;
\ No newline at end of file
@@ -1 +1,2 @@
+pub mod apikey;
 pub mod echo;
\ No newline at end of file
@@ -0,0 +1,293 @@
+use std::collections::{BTreeMap, HashMap};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use futures_util::future::{BoxFuture, FutureExt};
+use tonic::body::BoxBody;
+use tonic::codegen::{http, Body, Bytes};
+use tower::{Layer, Service};
+use tracing::{info, warn};
+use crate::config::SloOptions;
+use crate::metrics::{metrics, MethodLabels};
+use crate::server::Shed;
+
+const GRPC_STATUS: &str = "grpc-status";
+
+/// Service level objective of a method, e.g. `99.9%,250ms@99`: 99.9% of the
+/// requests succeed and 99% of them answer within 250ms. Either part may be
+/// left out.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Objective {
+    /// Percentage of the requests that must not fail on the server side.
+    pub success_rate: Option<f64>,
+    /// Latency threshold and the percentage of the requests that must answer within it.
+    pub latency: Option<(Duration, f64)>,
+}
+
+impl Objective {
+    pub fn parse(objective: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut parsed = Objective { success_rate: None, latency: None };
+
+        for part in objective.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+            match part.split_once('@') {
+                Some((threshold, percentile)) => {
+                    let threshold = parse_threshold(threshold.trim())?;
+                    parsed.latency = Some((threshold, parse_percentage(percentile.trim(), part)?));
+                }
+                None => {
+                    let rate = part
+                        .strip_suffix('%')
+                        .ok_or_else(|| format!("Invalid objective '{}', expected a success rate such as 99.9%", part))?;
+                    parsed.success_rate = Some(parse_percentage(rate.trim(), part)?);
+                }
+            }
+        }
+
+        if parsed.success_rate.is_none() && parsed.latency.is_none() {
+            return Err(format!("Invalid objective '{}', expected success%,latency@percentile such as 99.9%,250ms@99", objective).into());
+        }
+        Ok(parsed)
+    }
+
+    /// Share of the requests that may fail, between 0 and 1.
+    pub fn error_budget(&self) -> Option<f64> {
+        self.success_rate.map(|rate| 1.0 - rate / 100.0)
+    }
+
+    /// Share of the requests that may be slower than the threshold, between 0 and 1.
+    pub fn latency_budget(&self) -> Option<f64> {
+        self.latency.map(|(_, percentile)| 1.0 - percentile / 100.0)
+    }
+}
+
+fn parse_percentage(value: &str, part: &str) -> Result<f64, Box<dyn std::error::Error>> {
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|value| *value > 0.0 && *value < 100.0)
+        .ok_or_else(|| format!("Invalid objective '{}', expected a percentage between 0 and 100", part).into())
+}
+
+fn parse_threshold(threshold: &str) -> Result<Duration, Box<dyn std::error::Error>> {
+    let unit_start = threshold.find(|c: char| !c.is_ascii_digit()).unwrap_or(threshold.len());
+    let (count, unit) = threshold.split_at(unit_start);
+    let count = count.parse::<u64>().ok().filter(|count| *count > 0);
+    match (count, unit) {
+        (Some(count), "ms") => Ok(Duration::from_millis(count)),
+        (Some(count), "s") => Ok(Duration::from_secs(count)),
+        _ => Err(format!("Invalid latency '{}', expected a positive duration in ms or s", threshold).into()),
+    }
+}
+
+/// Objectives of the served methods, recorded by the [`SloLayer`] as the
+/// `slo_requests`, `slo_errors` and `slo_slow_requests` counters.
+#[derive(Debug, Clone, Default)]
+pub struct Objectives {
+    methods: Arc<BTreeMap<String, Objective>>,
+}
+
+impl Objectives {
+    /// Parses `;` separated rules of the form `method=objective`. The method
+    /// is a gRPC path such as `/echo.v1.EchoService/UnaryEcho`, or
+    /// `/echo.v1.EchoService/*` for each method of the service. See
+    /// [`Objective::parse`] for the objective.
+    pub fn parse_rules(rules: &str) -> Result<HashMap<String, Objective>, Box<dyn std::error::Error>> {
+        let mut objectives = HashMap::new();
+
+        for rule in rules.split(';').map(str::trim).filter(|rule| !rule.is_empty()) {
+            let (method, objective) = rule
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid objective '{}', expected method=objective", rule))?;
+            let method = method.trim();
+            if !method.starts_with('/') {
+                return Err(format!("Invalid method '{}' in objectives, expected /package.Service/Method", method).into());
+            }
+
+            objectives.insert(method.to_string(), Objective::parse(objective)?);
+        }
+
+        Ok(objectives)
+    }
+
+    /// Resolves the objective of each of `methods`, the gRPC paths the server serves.
+    pub fn new(options: &SloOptions, methods: &[String]) -> Result<Self, Box<dyn std::error::Error>> {
+        let rules = Objectives::parse_rules(&options.methods)?;
+        let default = match options.default_objective.as_str() {
+            "" => None,
+            objective => Some(Objective::parse(objective)?),
+        };
+        for method in rules.keys() {
+            let service = method.strip_suffix("/*").unwrap_or(method);
+            if !methods.iter().any(|served| served == method || served.starts_with(&format!("{}/", service))) {
+                warn!("Objective set for {}, which isn't served", method);
+            }
+        }
+
+        let mut objectives = BTreeMap::new();
+        for method in methods {
+            let service_rule = method.rsplit_once('/').map(|(service, _)| format!("{}/*", service));
+            let objective = rules
+                .get(method)
+                .or_else(|| service_rule.and_then(|rule| rules.get(&rule)))
+                .copied()
+                .or(default);
+
+            if let Some(objective) = objective {
+                info!("Objective of {}: {:?}", method, objective);
+                objectives.insert(method.clone(), objective);
+            }
+        }
+
+        Ok(Objectives { methods: Arc::new(objectives) })
+    }
+
+    pub fn get(&self, method: &str) -> Option<&Objective> {
+        self.methods.get(method)
+    }
+
+    /// Every method with an objective, ordered by method.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Objective)> {
+        self.methods.iter()
+    }
+}
+
+/// Statuses that spend the error budget. Other failures are the caller's doing.
+fn is_server_error(code: tonic::Code) -> bool {
+    matches!(
+        code,
+        tonic::Code::Unknown
+            | tonic::Code::DeadlineExceeded
+            | tonic::Code::Internal
+            | tonic::Code::Unavailable
+            | tonic::Code::DataLoss
+    )
+}
+
+/// Counts the requests, server errors and slow responses of every method
+/// with an [`Objective`]. Latency runs until the response headers are sent.
+/// Requests cancelled by the client aren't counted. Goes above the
+/// [`CatchPanicLayer`](crate::server::CatchPanicLayer) so panics count as errors.
+///
+/// Server errors are `UNKNOWN`, `DEADLINE_EXCEEDED`, `INTERNAL`, `UNAVAILABLE`
+/// and `DATA_LOSS`, plus the `RESOURCE_EXHAUSTED` of requests [`Shed`] by the
+/// concurrency limits. Other statuses, rate limited requests and
+/// `UNIMPLEMENTED` included, are the caller's doing.
+#[derive(Debug, Clone)]
+pub struct SloLayer {
+    objectives: Objectives,
+}
+
+impl SloLayer {
+    pub fn new(objectives: Objectives) -> Self {
+        SloLayer { objectives }
+    }
+}
+
+impl<S> Layer<S> for SloLayer {
+    type Service = SloService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        SloService { inner, objectives: self.objectives.clone() }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SloService<S> {
+    inner: S,
+    objectives: Objectives,
+}
+
+impl<S, B> Service<http::Request<B>> for SloService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let method = request.uri().path().to_string();
+        let objective = self.objectives.get(&method).copied();
+        let started = Instant::now();
+        let future = self.inner.call(request);
+
+        let Some(objective) = objective else {
+            return future.boxed();
+        };
+        async move {
+            let response = future.await?;
+            let outcome = Outcome { method, objective, latency: started.elapsed() };
+            // Trailers-only responses carry the status in their headers
+            Ok(match response.headers().get(GRPC_STATUS) {
+                Some(status) => {
+                    let shed = response.extensions().get::<Shed>().is_some();
+                    outcome.record(tonic::Code::from_bytes(status.as_bytes()), shed);
+                    response
+                }
+                None => response.map(|inner| BoxBody::new(SloBody { inner, outcome: Some(outcome) })),
+            })
+        }
+        .boxed()
+    }
+}
+
+struct Outcome {
+    method: String,
+    objective: Objective,
+    latency: Duration,
+}
+
+impl Outcome {
+    /// `shed` is set for the requests shed by the concurrency limits.
+    fn record(self, status: tonic::Code, shed: bool) {
+        if status == tonic::Code::Cancelled {
+            return;
+        }
+
+        let labels = MethodLabels { method: self.method };
+        metrics().slo_requests.get_or_create(&labels).inc();
+        if shed || is_server_error(status) {
+            metrics().slo_errors.get_or_create(&labels).inc();
+        }
+        if self.objective.latency.is_some_and(|(threshold, _)| self.latency > threshold) {
+            metrics().slo_slow_requests.get_or_create(&labels).inc();
+        }
+    }
+}
+
+/// Streams the response and records the outcome from its trailers.
+struct SloBody {
+    inner: BoxBody,
+    outcome: Option<Outcome>,
+}
+
+impl Body for SloBody {
+    type Data = Bytes;
+    type Error = tonic::Status;
+
+    fn poll_data(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        Pin::new(&mut self.inner).poll_data(cx)
+    }
+
+    fn poll_trailers(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<Option<http::HeaderMap>, Self::Error>> {
+        let this = &mut *self;
+        let poll = Pin::new(&mut this.inner).poll_trailers(cx);
+        if let Poll::Ready(Ok(Some(trailers))) = &poll {
+            if let (Some(status), Some(outcome)) = (trailers.get(GRPC_STATUS), this.outcome.take()) {
+                outcome.record(tonic::Code::from_bytes(status.as_bytes()), false);
+            }
+        }
+        poll
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+}
@@ -0,0 +1,139 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::future::{BoxFuture, FutureExt};
+use tonic::body::BoxBody;
+use tonic::codegen::{http, Body, Bytes};
+use tonic::transport::server::{TcpConnectInfo, TlsConnectInfo};
+use tower::{Layer, Service};
+use tracing::field::{self, Empty};
+use tracing::{Instrument, Span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use crate::server::RequestId;
+use crate::telemetry::extract_context;
+
+const GRPC_STATUS: &str = "grpc-status";
+
+/// Runs every RPC in an `rpc` span carrying its `method`, `peer`,
+/// `request_id` and, once known, `status`, so log records emitted while
+/// handling the RPC include them. Goes below the [`RequestIdLayer`](crate::server::RequestIdLayer).
+/// When traces are exported, the span continues the caller's `traceparent`.
+#[derive(Debug, Clone, Default)]
+pub struct RpcSpanLayer;
+
+impl RpcSpanLayer {
+    pub fn new() -> Self {
+        RpcSpanLayer
+    }
+}
+
+impl<S> Layer<S> for RpcSpanLayer {
+    type Service = RpcSpanService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcSpanService { inner }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RpcSpanService<S> {
+    inner: S,
+}
+
+impl<S, B> Service<http::Request<B>> for RpcSpanService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let span = tracing::info_span!(
+            "rpc",
+            method = %request.uri().path(),
+            peer = Empty,
+            request_id = Empty,
+            status = Empty,
+            otel.name = %request.uri().path(),
+            otel.kind = "server",
+            otel.status_code = Empty,
+        );
+        span.set_parent(extract_context(request.headers()));
+        if let Some(peer) = peer_addr(request.extensions()) {
+            span.record("peer", field::display(peer));
+        }
+        if let Some(id) = request.extensions().get::<RequestId>() {
+            span.record("request_id", field::display(id));
+        }
+
+        let future = span.in_scope(|| self.inner.call(request));
+        async move {
+            let response = future.await?;
+            let span = Span::current();
+            // Trailers-only responses carry the status in their headers
+            Ok(match response.headers().get(GRPC_STATUS) {
+                Some(status) => {
+                    record_status(&span, status);
+                    response
+                }
+                None => response.map(|inner| BoxBody::new(RpcBody { inner, span })),
+            })
+        }
+        .instrument(span)
+        .boxed()
+    }
+}
+
+pub(crate) fn peer_addr(extensions: &http::Extensions) -> Option<std::net::SocketAddr> {
+    extensions
+        .get::<TcpConnectInfo>()
+        .or_else(|| extensions.get::<TlsConnectInfo<TcpConnectInfo>>().map(|info| info.get_ref()))
+        .and_then(|info| info.remote_addr())
+}
+
+fn record_status(span: &Span, status: &http::HeaderValue) {
+    let code = tonic::Code::from_bytes(status.as_bytes());
+    span.record("status", field::debug(code));
+    if code != tonic::Code::Ok {
+        span.record("otel.status_code", "ERROR");
+    }
+}
+
+/// Streams the response inside the RPC span and records the status from its trailers.
+struct RpcBody {
+    inner: BoxBody,
+    span: Span,
+}
+
+impl Body for RpcBody {
+    type Data = Bytes;
+    type Error = tonic::Status;
+
+    fn poll_data(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        let this = &mut *self;
+        let _entered = this.span.enter();
+        Pin::new(&mut this.inner).poll_data(cx)
+    }
+
+    fn poll_trailers(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<Option<http::HeaderMap>, Self::Error>> {
+        let this = &mut *self;
+        let _entered = this.span.enter();
+        let poll = Pin::new(&mut this.inner).poll_trailers(cx);
+        if let Poll::Ready(Ok(Some(trailers))) = &poll {
+            if let Some(status) = trailers.get(GRPC_STATUS) {
+                record_status(&this.span, status);
+            }
+        }
+        poll
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+}
@@ -0,0 +1,335 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use tracing::{debug, info, warn};
+use futures_util::StreamExt;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_rustls::rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use tokio_rustls::rustls::crypto::{ring, verify_tls12_signature, verify_tls13_signature, WebPkiSupportedAlgorithms};
+use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use tokio_rustls::rustls::server::WebPkiClientVerifier;
+use tokio_rustls::rustls::{
+    ClientConfig, ClientConnection, Connection as TlsConnection, DigitallySignedStruct, RootCertStore, ServerConfig,
+    ServerConnection, SignatureScheme,
+};
+use tokio_rustls::server::TlsStream;
+use tokio_stream::wrappers::ReceiverStream;
+use crate::config::TlsOptions;
+use crate::metrics::{metrics, ReloadLabels};
+use crate::report_error;
+use crate::server::{Connection, Incoming, ShutdownTrigger};
+
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+const ACCEPT_BACKLOG: usize = 128;
+
+/// Where the server certificate, its key and the optional client CA bundle come from.
+#[derive(Debug, Clone)]
+pub enum TlsSource {
+    /// PEM files, watched for changes.
+    Files {
+        cert: PathBuf,
+        key: PathBuf,
+        ca_cert: Option<PathBuf>,
+    },
+    /// PEM contents, fixed for the lifetime of the process.
+    Pem {
+        cert: String,
+        key: String,
+        ca_cert: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct TlsSettings {
+    pub source: TlsSource,
+    /// How often the files are checked for changes.
+    pub reload_interval: Duration,
+    /// Also offers `http/1.1` over ALPN, for gRPC-Web clients.
+    pub http1: bool,
+}
+
+impl TlsSettings {
+    /// Prefers the PEM files over PEM contents. Returns `None` when neither
+    /// a certificate nor a key is configured. A CA enables mTLS.
+    pub fn from_options(options: &TlsOptions) -> Option<Self> {
+        let source = match (&options.cert_file, &options.key_file) {
+            (Some(cert), Some(key)) => TlsSource::Files {
+                cert: cert.clone(),
+                key: key.clone(),
+                ca_cert: options.ca_cert_file.clone(),
+            },
+            _ => match (&options.cert, &options.key) {
+                (Some(cert), Some(key)) => TlsSource::Pem {
+                    cert: cert.clone(),
+                    key: key.clone(),
+                    ca_cert: options.ca_cert.clone(),
+                },
+                _ => return None,
+            },
+        };
+
+        Some(TlsSettings {
+            source,
+            reload_interval: Duration::from_secs(options.reload_interval_secs),
+            http1: false,
+        })
+    }
+}
+
+/// PEM material as read from the source, compared between polls to detect changes.
+#[derive(Debug, Clone, PartialEq)]
+struct TlsMaterial {
+    cert: Vec<u8>,
+    key: Vec<u8>,
+    ca_cert: Option<Vec<u8>>,
+}
+
+impl TlsMaterial {
+    fn read(source: &TlsSource) -> io::Result<Self> {
+        match source {
+            TlsSource::Files { cert, key, ca_cert } => Ok(TlsMaterial {
+                cert: fs::read(cert)?,
+                key: fs::read(key)?,
+                ca_cert: ca_cert.as_ref().map(fs::read).transpose()?,
+            }),
+            TlsSource::Pem { cert, key, ca_cert } => Ok(TlsMaterial {
+                cert: cert.clone().into_bytes(),
+                key: key.clone().into_bytes(),
+                ca_cert: ca_cert.clone().map(String::into_bytes),
+            }),
+        }
+    }
+
+    fn server_config(&self, http1: bool) -> Result<ServerConfig, Box<dyn std::error::Error + Send + Sync>> {
+        let certs = rustls_pemfile::certs(&mut self.cert.as_slice()).collect::<Result<Vec<_>, _>>()?;
+        if certs.is_empty() {
+            return Err("No certificate found in TLS certificate".into());
+        }
+        let key = rustls_pemfile::private_key(&mut self.key.as_slice())?
+            .ok_or("No private key found in TLS key")?;
+        check_key_matches(&certs, &key)?;
+
+        let builder = ServerConfig::builder();
+        let builder = match &self.ca_cert {
+            Some(ca_cert) => {
+                let mut roots = RootCertStore::empty();
+                for cert in rustls_pemfile::certs(&mut ca_cert.as_slice()) {
+                    roots.add(cert?)?;
+                }
+                builder.with_client_cert_verifier(WebPkiClientVerifier::builder(Arc::new(roots)).build()?)
+            }
+            None => builder.with_no_client_auth(),
+        };
+
+        let mut config = builder.with_single_cert(certs, key)?;
+        config.alpn_protocols = vec![b"h2".to_vec()];
+        if http1 {
+            config.alpn_protocols.push(b"http/1.1".to_vec());
+        }
+        Ok(config)
+    }
+}
+
+/// Runs a handshake in memory with `certs` and `key`, so a certificate
+/// renewed without its key is refused up front instead of failing every
+/// handshake once swapped in.
+fn check_key_matches(certs: &[CertificateDer<'static>], key: &PrivateKeyDer<'static>) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let server = ServerConfig::builder().with_no_client_auth().with_single_cert(certs.to_vec(), key.clone_key())?;
+    let verifier = SignatureOnly(ring::default_provider().signature_verification_algorithms);
+    let client = ClientConfig::builder()
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(verifier))
+        .with_no_client_auth();
+
+    let mut client = TlsConnection::Client(ClientConnection::new(Arc::new(client), ServerName::try_from("localhost")?)?);
+    let mut server = TlsConnection::Server(ServerConnection::new(Arc::new(server))?);
+    // A TLS 1.3 handshake takes two round trips
+    for _ in 0..4 {
+        if !client.is_handshaking() && !server.is_handshaking() {
+            return Ok(());
+        }
+        transfer(&mut client, &mut server)?;
+        transfer(&mut server, &mut client)?;
+    }
+    Err("TLS handshake with the certificate did not complete".into())
+}
+
+fn transfer(from: &mut TlsConnection, to: &mut TlsConnection) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let mut buffer = Vec::new();
+    while from.wants_write() {
+        from.write_tls(&mut buffer)?;
+    }
+    let mut records = buffer.as_slice();
+    while !records.is_empty() {
+        to.read_tls(&mut records)?;
+        to.process_new_packets().map_err(|e| format!("TLS certificate and key don't match: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Trusts any certificate but checks the handshake signatures against it,
+/// which proves the server holds the certificate's key.
+#[derive(Debug)]
+struct SignatureOnly(WebPkiSupportedAlgorithms);
+
+impl ServerCertVerifier for SignatureOnly {
+    fn verify_server_cert(
+        &self,
+        _: &CertificateDer<'_>,
+        _: &[CertificateDer<'_>],
+        _: &ServerName<'_>,
+        _: &[u8],
+        _: UnixTime,
+    ) -> Result<ServerCertVerified, tokio_rustls::rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, tokio_rustls::rustls::Error> {
+        verify_tls12_signature(message, cert, dss, &self.0)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, tokio_rustls::rustls::Error> {
+        verify_tls13_signature(message, cert, dss, &self.0)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.supported_schemes()
+    }
+}
+
+/// Terminates TLS for the listeners. The certificate can be swapped while the
+/// server runs: new handshakes use the latest config, established connections
+/// keep the one they were accepted with.
+#[derive(Clone)]
+pub struct TlsAcceptor {
+    settings: Arc<TlsSettings>,
+    config: Arc<RwLock<Arc<ServerConfig>>>,
+    material: Arc<Mutex<TlsMaterial>>,
+}
+
+impl TlsAcceptor {
+    pub fn new(settings: TlsSettings) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let material = TlsMaterial::read(&settings.source)?;
+        let config = material.server_config(settings.http1)?;
+
+        match &settings.source {
+            TlsSource::Files { ca_cert: Some(_), .. } | TlsSource::Pem { ca_cert: Some(_), .. } => {
+                info!("Configuring TLS with custom CA...")
+            }
+            _ => info!("Configuring TLS with official CAs..."),
+        }
+
+        Ok(TlsAcceptor {
+            settings: Arc::new(settings),
+            config: Arc::new(RwLock::new(Arc::new(config))),
+            material: Arc::new(Mutex::new(material)),
+        })
+    }
+
+    /// Re-reads the TLS material and swaps the config in if it changed.
+    /// Returns `Ok(false)` when nothing changed. Invalid material leaves the
+    /// current config in place.
+    pub fn reload(&self) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let material = TlsMaterial::read(&self.settings.source)?;
+        let mut current = self.material.lock().unwrap();
+        if *current == material {
+            return Ok(false);
+        }
+
+        // Remember the new material even if it is invalid, so a broken file is reported once
+        *current = material.clone();
+        let config = material.server_config(self.settings.http1)?;
+        *self.config.write().unwrap() = Arc::new(config);
+        Ok(true)
+    }
+
+    /// Polls the TLS files every `reload_interval` until `shutdown` is triggered.
+    /// Does nothing for PEM contents passed through the environment.
+    pub fn spawn_reloader(&self, shutdown: ShutdownTrigger) -> Option<JoinHandle<()>> {
+        if !matches!(self.settings.source, TlsSource::Files { .. }) {
+            return None;
+        }
+
+        let acceptor = self.clone();
+        Some(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(acceptor.settings.reload_interval);
+            ticker.tick().await;
+
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => acceptor.reload_and_report(),
+                    _ = shutdown.triggered() => break,
+                }
+            }
+        }))
+    }
+
+    fn reload_and_report(&self) {
+        match self.reload() {
+            Ok(false) => {}
+            Ok(true) => {
+                info!("TLS certificate reloaded");
+                metrics().tls_reloads.get_or_create(&ReloadLabels { result: "success" }).inc();
+            }
+            Err(e) => {
+                warn!("Failed to reload TLS certificate, keeping the current one");
+                report_error(e.as_ref());
+                metrics().tls_reloads.get_or_create(&ReloadLabels { result: "failure" }).inc();
+            }
+        }
+    }
+
+    /// Performs the TLS handshake for every connection of `incoming`. Handshakes
+    /// run concurrently so a slow client doesn't hold up the others.
+    pub fn accept(&self, mut incoming: Incoming) -> ReceiverStream<io::Result<TlsStream<Connection>>> {
+        let (tx, rx) = mpsc::channel(ACCEPT_BACKLOG);
+        let config = self.config.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let connection = tokio::select! {
+                    connection = incoming.next() => connection,
+                    // The server stopped, release the listener
+                    _ = tx.closed() => break,
+                };
+
+                let connection = match connection {
+                    Some(Ok(connection)) => connection,
+                    Some(Err(e)) => {
+                        warn!("Failed to accept connection: {}", e);
+                        continue;
+                    }
+                    None => break,
+                };
+
+                let acceptor = tokio_rustls::TlsAcceptor::from(config.read().unwrap().clone());
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(connection)).await {
+                        Ok(Ok(stream)) => {
+                            let _ = tx.send(Ok(stream)).await;
+                        }
+                        Ok(Err(e)) => debug!("TLS handshake failed: {}", e),
+                        Err(_) => debug!("TLS handshake timed out"),
+                    }
+                });
+            }
+        });
+
+        ReceiverStream::new(rx)
+    }
+}
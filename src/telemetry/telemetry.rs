@@ -0,0 +1,63 @@
+use opentelemetry::propagation::Extractor;
+use opentelemetry::trace::TraceError;
+use opentelemetry::{global, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{self, Sampler};
+use opentelemetry_sdk::{runtime, Resource};
+use tonic::codegen::http;
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::registry::LookupSpan;
+use crate::config::OtelOptions;
+
+/// Layer exporting spans in batches to the OTLP/HTTP collector at
+/// `options.endpoint`, or `None` when no endpoint is set. Also makes the
+/// server continue the traces of incoming W3C `traceparent` metadata, see
+/// [`extract_context`].
+pub fn otel_layer<S>(options: &OtelOptions) -> Result<Option<OpenTelemetryLayer<S, trace::Tracer>>, TraceError>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    let endpoint = match &options.endpoint {
+        Some(endpoint) => endpoint,
+        None => return Ok(None),
+    };
+
+    global::set_text_map_propagator(TraceContextPropagator::new());
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(opentelemetry_otlp::new_exporter().http().with_endpoint(endpoint.trim_end_matches('/')))
+        .with_trace_config(
+            trace::config()
+                // Sampled callers are always traced, whatever the ratio
+                .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(options.sample_ratio))))
+                .with_resource(Resource::new([KeyValue::new("service.name", options.service_name.clone())])),
+        )
+        .install_batch(runtime::Tokio)?;
+
+    Ok(Some(tracing_opentelemetry::layer().with_tracer(tracer)))
+}
+
+/// Trace context of the caller, from its `traceparent` and `tracestate` metadata.
+pub fn extract_context(headers: &http::HeaderMap) -> opentelemetry::Context {
+    global::get_text_map_propagator(|propagator| propagator.extract(&HeaderExtractor(headers)))
+}
+
+/// Exports the spans still buffered. Blocks until the collector answers or
+/// the export times out.
+pub async fn shutdown_tracing() {
+    let _ = tokio::task::spawn_blocking(global::shutdown_tracer_provider).await;
+}
+
+struct HeaderExtractor<'a>(&'a http::HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|name| name.as_str()).collect()
+    }
+}
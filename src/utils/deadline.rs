@@ -0,0 +1,99 @@
+use std::future::Future;
+use std::time::Duration;
+
+use tokio::time::Instant;
+use tonic::codegen::http;
+use tonic::metadata::MetadataMap;
+use crate::errors::ApiError;
+
+static GRPC_TIMEOUT: &str = "grpc-timeout";
+
+/// Time taken off the client's timeout, so the `DEADLINE_EXCEEDED` status
+/// reaches the client before it gives up on its own.
+const DEADLINE_MARGIN: Duration = Duration::from_millis(10);
+
+/// The point in time after which the client no longer waits for a response.
+/// Requests without a `grpc-timeout` have no deadline.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Deadline {
+    at: Option<Instant>,
+}
+
+impl Deadline {
+    pub const NONE: Deadline = Deadline { at: None };
+
+    pub fn after(timeout: Duration) -> Self {
+        Deadline { at: Some(Instant::now() + timeout) }
+    }
+
+    pub fn from_metadata(metadata: &MetadataMap) -> Self {
+        Deadline::from_timeout(metadata.get(GRPC_TIMEOUT).and_then(|value| value.to_str().ok()))
+    }
+
+    /// For middleware, which sees the HTTP request before tonic decodes it.
+    pub fn from_headers(headers: &http::HeaderMap) -> Self {
+        Deadline::from_timeout(headers.get(GRPC_TIMEOUT).and_then(|value| value.to_str().ok()))
+    }
+
+    fn from_timeout(grpc_timeout: Option<&str>) -> Self {
+        match grpc_timeout.and_then(parse_grpc_timeout) {
+            Some(timeout) => Deadline::after(timeout.saturating_sub(DEADLINE_MARGIN)),
+            None => Deadline::NONE,
+        }
+    }
+
+    pub fn from_request<T>(request: &tonic::Request<T>) -> Self {
+        Deadline::from_metadata(request.metadata())
+    }
+
+    /// Time left before the deadline, `None` without a deadline.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.at.map(|at| at.saturating_duration_since(Instant::now()))
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.remaining().is_some_and(|remaining| remaining.is_zero())
+    }
+
+    /// Runs `future` until the deadline. `operation` names what timed out in
+    /// the `DEADLINE_EXCEEDED` error.
+    pub async fn run<F: Future>(&self, operation: &str, future: F) -> Result<F::Output, ApiError> {
+        let at = match self.at {
+            Some(at) => at,
+            None => return Ok(future.await),
+        };
+
+        // `timeout_at` polls the future once even when the deadline has passed
+        if self.is_expired() {
+            return Err(ApiError::DeadlineExceeded(operation.to_string()));
+        }
+
+        tokio::time::timeout_at(at, future)
+            .await
+            .map_err(|_| ApiError::DeadlineExceeded(operation.to_string()))
+    }
+}
+
+/// Parses a `grpc-timeout` value: at most 8 digits followed by a unit,
+/// `H`, `M`, `S`, `m`, `u` or `n`.
+pub fn parse_grpc_timeout(value: &str) -> Option<Duration> {
+    if value.len() < 2 || value.len() > 9 {
+        return None;
+    }
+
+    let (amount, unit) = value.split_at(value.len() - 1);
+    if !amount.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let amount = amount.parse::<u64>().ok()?;
+
+    match unit {
+        "H" => Some(Duration::from_secs(amount * 60 * 60)),
+        "M" => Some(Duration::from_secs(amount * 60)),
+        "S" => Some(Duration::from_secs(amount)),
+        "m" => Some(Duration::from_millis(amount)),
+        "u" => Some(Duration::from_micros(amount)),
+        "n" => Some(Duration::from_nanos(amount)),
+        _ => None,
+    }
+}
@@ -1 +1,2 @@
+pub mod deadline;
 pub mod validation;
\ No newline at end of file
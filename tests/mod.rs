@@ -5,9 +5,10 @@ use redis::Client;
 use sqlx::migrate::Migrator;
 use tokio::sync::oneshot;
 use tonic::transport::Server;
-use protos::echo::v1::echo_service_server::EchoServiceServer;
-use rust_server::database::{CacheClient, PgPool};
-use rust_server::server::services::v1::echo::echo_service::EchoServiceServerImpl;
+use tonic::transport::server::Routes;
+use rust_server::config::DatabaseOptions;
+use rust_server::database::{self, CacheClient, PgPool};
+use rust_server::server::{spawn_health_checker, AppBuilder, AppState, ShutdownTrigger};
 use futures_util::FutureExt;
 
 pub mod rpcs;
@@ -20,7 +21,8 @@ struct TestContext {
     db_name: String,
     addr: SocketAddr,
     url: String,
-    service: EchoServiceServerImpl,
+    state: AppState,
+    shutdown: ShutdownTrigger,
 }
 
 // TODO: Add mock redis server
@@ -30,12 +32,13 @@ impl TestContext {
         let pool = PgPool::connect(&format!("{}/postgres", db_url)).await.expect("Cannot connect to postgres database.");
 
         let query = format!("DROP DATABASE IF EXISTS {}", db_name);
-        sqlx::query(&query).execute(&pool).await.expect(&format!("Could not drop database {}", db_name));
+        sqlx::query(&query).execute(&pool).await.unwrap_or_else(|_| panic!("Could not drop database {}", db_name));
 
         let query = format!("CREATE DATABASE {}", db_name);
-        sqlx::query(&query).execute(&pool).await.expect(&format!("Could not create database {}", db_name));
+        sqlx::query(&query).execute(&pool).await.unwrap_or_else(|_| panic!("Could not create database {}", db_name));
 
-        let pool = Arc::new(PgPool::connect(&format!("{}/{}", db_url, db_name)).await.expect("Cannot connect to new database"));
+        let options = DatabaseOptions { url: format!("{}/{}", db_url, db_name), ..Default::default() };
+        let pool = Arc::new(database::connect(&options).await.expect("Cannot connect to new database"));
 
         MIGRATIONS.run(pool.as_ref()).await.expect("Failed to run migrations");
 
@@ -53,19 +56,30 @@ impl TestContext {
             db_name: db_name.to_string(),
             addr,
             url,
-            service: EchoServiceServerImpl { pool: pool.clone(), cache: cache_client.clone() },
+            state: AppState { pool: pool.clone(), cache: cache_client.clone() },
+            shutdown: ShutdownTrigger::new(),
         }
     }
 
+    #[allow(dead_code)]
     async fn mock_database<F, Fut>(&self, mut f: F)
     where
         F: FnMut(&PgPool) -> Fut,
         Fut: std::future::Future<Output = ()>
     {
-        f(&self.service.pool).await;
+        f(&self.state.pool).await;
+    }
+
+    /// The routes production serves, for tests that run their own server.
+    fn routes(&self) -> Routes {
+        AppBuilder::new().build(&self.state).routes
     }
 
     async fn cleanup(&self) {
+        // Stop the health checker and close the pool so nothing reconnects before the drop
+        self.shutdown.trigger();
+        self.state.pool.close().await;
+
         let pool = PgPool::connect(&format!("{}/postgres", self.db_url)).await.expect("Cannot connect to postgres database.");
 
         let disconnect_users = format!(
@@ -76,7 +90,7 @@ impl TestContext {
         sqlx::query(&disconnect_users).execute(&pool).await.unwrap();
 
         let query = format!("DROP DATABASE {}", self.db_name);
-        sqlx::query(&query).execute(&pool).await.expect(&format!("Couldn't drop database {}", self.db_name));
+        sqlx::query(&query).execute(&pool).await.unwrap_or_else(|_| panic!("Couldn't drop database {}", self.db_name));
     }
 }
 
@@ -88,11 +102,20 @@ async fn setup_test_context(name: &str, port: u16) -> (TestContext, oneshot::Sen
         port,
     ).await;
     let (tx, rx) = oneshot::channel();
-    let echo_service = ctx.service.clone();
+    let app = AppBuilder::new().build(&ctx.state);
+
+    spawn_health_checker(
+        app.health,
+        app.health_names,
+        ctx.state.pool.clone(),
+        ctx.state.cache.clone(),
+        ctx.shutdown.clone(),
+        std::time::Duration::from_millis(50),
+    );
 
     let jh = tokio::spawn(async move {
         Server::builder()
-            .add_service(EchoServiceServer::new(echo_service))
+            .add_routes(app.routes)
             .serve_with_shutdown(ctx.addr, rx.map(|_| ()))
             .await
             .unwrap();
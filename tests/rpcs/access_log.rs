@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::Layer;
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::{ServerStreamingEchoRequest, UnaryEchoRequest};
+use rust_server::config::AccessLogOptions;
+use rust_server::server::{serve_listeners, AccessLog, ListenerConfig, Middleware, ShutdownTrigger};
+use crate::setup_test_context;
+
+type Fields = HashMap<String, String>;
+
+/// Collects the fields of every `access_log` event.
+#[derive(Clone, Default)]
+struct AccessLogEvents(Arc<Mutex<Vec<Fields>>>);
+
+struct Visitor<'a>(&'a mut Fields);
+
+impl Visit for Visitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.insert(field.name().to_string(), format!("{:?}", value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+}
+
+impl<S: Subscriber> Layer<S> for AccessLogEvents {
+    fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+        if event.metadata().target() == "access_log" {
+            let mut fields = Fields::new();
+            fields.insert("level".to_string(), event.metadata().level().to_string());
+            event.record(&mut Visitor(&mut fields));
+            self.0.lock().unwrap().push(fields);
+        }
+    }
+}
+
+async fn call_echo(options: AccessLogOptions, port: u16, routes: tonic::transport::server::Routes) -> Result<Vec<Fields>, Box<dyn std::error::Error>> {
+    let events = AccessLogEvents::default();
+    let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(events.clone()));
+
+    let middleware = Middleware { access_log: AccessLog::new(&options), ..Default::default() };
+    let shutdown = ShutdownTrigger::new();
+    let addr = format!("127.0.0.1:{}", port);
+    let server = serve_listeners(routes, &[ListenerConfig::parse(&addr)?], None, middleware, shutdown.clone())?;
+
+    let mut client = EchoServiceClient::connect(format!("http://{}", addr)).await?;
+    client.unary_echo(UnaryEchoRequest { message: "hello".to_string() }).await?;
+    client.unary_echo(UnaryEchoRequest { message: String::new() }).await.unwrap_err();
+    let request = ServerStreamingEchoRequest { message: "hello".to_string(), count: 2, interval_ms: 50 };
+    let mut stream = client.server_streaming_echo(request).await?.into_inner();
+    while stream.message().await?.is_some() {}
+    drop(client);
+    shutdown.trigger();
+    server.await?;
+
+    let events = events.0.lock().unwrap().clone();
+    Ok(events)
+}
+
+#[tokio::test]
+async fn logs_completed_rpcs() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("logs_completed_rpcs", 50251).await;
+
+    let events = call_echo(AccessLogOptions::default(), 50252, ctx.routes()).await?;
+    assert_eq!(events.len(), 3);
+    let ok = &events[0];
+    assert_eq!(ok["level"], "INFO");
+    assert_eq!(ok["method"], "/echo.v1.EchoService/UnaryEcho");
+    assert_eq!(ok["status"], "Ok");
+    assert!(ok["peer"].starts_with("127.0.0.1:"));
+    assert!(!ok.contains_key("error"));
+    // A 5 byte frame header around the 7 byte message
+    assert_eq!(ok["request_bytes"], "12");
+    assert!(ok["response_bytes"].parse::<u64>()? > 5);
+    let invalid = &events[1];
+    assert_eq!(invalid["status"], "InvalidArgument");
+    assert_eq!(invalid["error"], "ValidationError");
+    assert_eq!(invalid["response_bytes"], "0");
+    let stream = &events[2];
+    assert_eq!(stream["method"], "/echo.v1.EchoService/ServerStreamingEcho");
+    assert_eq!(stream["status"], "Ok");
+    assert!(stream["latency_ms"].parse::<f64>()? >= 50.0);
+
+    // Failed RPCs are logged whatever the sample ratio
+    let sampled = AccessLogOptions { sample_ratio: 0.0, ..Default::default() };
+    let events = call_echo(sampled, 50253, ctx.routes()).await?;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["status"], "InvalidArgument");
+
+    // Slow RPCs are warnings and are logged whatever the sample ratio
+    let slow = AccessLogOptions { sample_ratio: 0.0, slow_ms: 40, ..Default::default() };
+    let events = call_echo(slow, 50254, ctx.routes()).await?;
+    let stream = events.iter().find(|event| event["method"].ends_with("ServerStreamingEcho")).unwrap();
+    assert_eq!(stream["level"], "WARN");
+
+    let disabled = AccessLogOptions { enabled: false, ..Default::default() };
+    assert!(call_echo(disabled, 50255, ctx.routes()).await?.is_empty());
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
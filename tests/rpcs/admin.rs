@@ -0,0 +1,111 @@
+use std::time::Duration;
+use hyper::{Body, Client, Method, Request, StatusCode};
+use tracing_subscriber::EnvFilter;
+use tonic_health::pb::health_check_response::ServingStatus;
+use rust_server::cli::{healthcheck, HealthcheckArgs, HealthcheckTarget};
+use rust_server::config::Config;
+use rust_server::server::{admin_router, Admin, ShutdownTrigger};
+use rust_server::LogFilter;
+use crate::setup_test_context;
+
+const ADMIN: &str = "http://127.0.0.1:50258";
+
+async fn send(method: Method, path: &str, token: Option<&str>, body: &str) -> Result<(StatusCode, String), Box<dyn std::error::Error>> {
+    send_to(ADMIN, method, path, token, body).await
+}
+
+async fn send_to(admin: &str, method: Method, path: &str, token: Option<&str>, body: &str) -> Result<(StatusCode, String), Box<dyn std::error::Error>> {
+    let mut request = Request::builder().method(method).uri(format!("{}{}", admin, path));
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Bearer {}", token));
+    }
+    let response = Client::new().request(request.body(Body::from(body.to_string()))?).await?;
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body()).await?;
+    Ok((status, String::from_utf8(body.to_vec())?))
+}
+
+async fn get(path: &str) -> Result<(StatusCode, String), Box<dyn std::error::Error>> {
+    send(Method::GET, path, Some("secret"), "").await
+}
+
+#[tokio::test]
+async fn serves_admin_endpoints() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("serves_admin_endpoints", 50257).await;
+    // The handle only works while its layer is alive
+    let (_layer, log_filter) = LogFilter::new(EnvFilter::new("info"));
+    let mut config = Config::default();
+    config.metrics.admin_token = Some("secret".to_string());
+
+    let shutdown = ShutdownTrigger::new();
+    let app = admin_router(Admin {
+        state: ctx.state.clone(),
+        shutdown: shutdown.clone(),
+        config: config.redacted(),
+        log_filter: Some(log_filter),
+        token: config.metrics.admin_token.clone(),
+        timeout: Duration::from_secs(1),
+    });
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:50258").await?;
+    let server = tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    assert_eq!(send(Method::GET, "/metrics", None, "").await?.0, StatusCode::UNAUTHORIZED);
+    assert_eq!(send(Method::GET, "/config", Some("wrong"), "").await?.0, StatusCode::UNAUTHORIZED);
+    assert_eq!(get("/metrics").await?.0, StatusCode::OK);
+    // Probes don't need the token
+    assert_eq!(send(Method::GET, "/livez", None, "").await?, (StatusCode::OK, "ok".to_string()));
+    assert_eq!(send(Method::GET, "/readyz", None, "").await?.0, StatusCode::OK);
+
+    let (status, body) = get("/readyz").await?;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let readiness: serde_json::Value = serde_json::from_str(&body)?;
+    assert_eq!(readiness["postgres"], "ok");
+    assert_eq!(readiness["redis"], "ok");
+    let target = HealthcheckTarget::parse_admin("127.0.0.1:50258");
+    let args = HealthcheckArgs { addr: None, service: String::new(), timeout: 1, ca_cert: None, domain: None, admin: true };
+    assert_eq!(healthcheck(&target, &args).await?, ServingStatus::Serving);
+
+    let buildinfo: serde_json::Value = serde_json::from_str(&get("/buildinfo").await?.1)?;
+    assert_eq!(buildinfo["version"], env!("CARGO_PKG_VERSION"));
+
+    let (_, dump) = get("/config").await?;
+    assert!(dump.contains("admin_token = \"<redacted>\""), "{}", dump);
+    assert!(!dump.contains("secret"), "{}", dump);
+
+    assert_eq!(get("/loglevel").await?, (StatusCode::OK, "info".to_string()));
+    let (status, filter) = send(Method::PUT, "/loglevel", Some("secret"), "warn,rust_server=debug").await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(get("/loglevel").await?.1, filter);
+    assert!(filter.contains("rust_server=debug"), "{}", filter);
+    assert_eq!(send(Method::PUT, "/loglevel", Some("secret"), "rust_server=loud").await?.0, StatusCode::BAD_REQUEST);
+    assert_eq!(get("/loglevel").await?.1, filter);
+
+    // Draining servers are no longer ready
+    shutdown.trigger();
+    assert_eq!(get("/readyz").await?.0, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(healthcheck(&target, &args).await?, ServingStatus::NotServing);
+
+    server.abort();
+
+    // Without a token the endpoints that leak the config or change the server are refused
+    let app = admin_router(Admin {
+        state: ctx.state.clone(),
+        shutdown: ShutdownTrigger::new(),
+        config: config.redacted(),
+        log_filter: None,
+        token: None,
+        timeout: Duration::from_secs(1),
+    });
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:50266").await?;
+    let server = tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    let open = "http://127.0.0.1:50266";
+    assert_eq!(send_to(open, Method::GET, "/metrics", None, "").await?.0, StatusCode::OK);
+    assert_eq!(send_to(open, Method::GET, "/config", None, "").await?.0, StatusCode::FORBIDDEN);
+    assert_eq!(send_to(open, Method::PUT, "/loglevel", None, "trace").await?.0, StatusCode::FORBIDDEN);
+    server.abort();
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use std::time::Duration;
+use tonic::transport::Channel;
+use protos::apikey::v1::api_key_service_client::ApiKeyServiceClient;
+use protos::apikey::v1::{CreateApiKeyRequest, ListApiKeysRequest, RevokeApiKeyRequest};
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::UnaryEchoRequest;
+use rust_server::config::AuthOptions;
+use rust_server::database::{get_connection, hash_api_key, insert_api_key};
+use rust_server::server::{serve_listeners, ApiKeyAuthenticator, JwtAuthenticator, ListenerConfig, Middleware, ShutdownTrigger};
+use rust_server::utils::deadline::Deadline;
+use crate::setup_test_context;
+
+fn with_key<T>(message: T, key: &str) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    request.metadata_mut().insert("x-api-key", key.parse().unwrap());
+    request
+}
+
+fn echo() -> UnaryEchoRequest {
+    UnaryEchoRequest { message: "hello".to_string() }
+}
+
+#[tokio::test]
+async fn manages_api_keys() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("manages_api_keys", 50237).await;
+    let mut conn = get_connection(&ctx.state.pool, Deadline::NONE).await?;
+    let (_, admin_key) = insert_api_key(&mut conn, "ops", &["api_keys:admin".to_string()], None).await?;
+    drop(conn);
+
+    let options = AuthOptions { enabled: true, api_keys: true, ..Default::default() };
+    let middleware = Middleware {
+        api_keys: ApiKeyAuthenticator::new(&ctx.state),
+        auth: JwtAuthenticator::new(&options).await?,
+        ..Default::default()
+    };
+    let shutdown = ShutdownTrigger::new();
+    let server = serve_listeners(ctx.routes(), &[ListenerConfig::parse("127.0.0.1:50238")?], None, middleware, shutdown.clone())?;
+
+    let channel = Channel::from_static("http://127.0.0.1:50238").connect().await?;
+    let mut api_keys = ApiKeyServiceClient::new(channel.clone());
+    let mut echo_client = EchoServiceClient::new(channel);
+
+    let status = echo_client.unary_echo(echo()).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    let status = echo_client.unary_echo(with_key(echo(), "ak_unknown")).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+    let create = CreateApiKeyRequest { owner: "billing".to_string(), scopes: vec!["echo:read".to_string()], expires_at: None };
+    let status = api_keys.create_api_key(create.clone()).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+    let created = api_keys.create_api_key(with_key(create.clone(), &admin_key)).await?.into_inner();
+    let key = created.api_key.unwrap();
+    assert!(created.secret.starts_with(&key.prefix));
+    assert_eq!(key.scopes, vec!["echo:read".to_string()]);
+
+    // Only the hash is stored
+    let stored: (String,) = sqlx::query_as("SELECT key_hash FROM api_keys WHERE id = $1::UUID")
+        .bind(&key.id)
+        .fetch_one(ctx.state.pool.as_ref())
+        .await?;
+    assert_eq!(stored.0, hash_api_key(&created.secret));
+
+    assert_eq!(echo_client.unary_echo(with_key(echo(), &created.secret)).await?.into_inner().message, "hello");
+    let status = api_keys.create_api_key(with_key(create, &created.secret)).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+    // Uses are recorded in the background
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let list = ListApiKeysRequest { owner: "billing".to_string(), include_revoked: false };
+    let listed = api_keys.list_api_keys(with_key(list.clone(), &admin_key)).await?.into_inner().api_keys;
+    assert_eq!(listed.len(), 1);
+    assert!(listed[0].last_used_at.is_some());
+
+    let revoked = api_keys
+        .revoke_api_key(with_key(RevokeApiKeyRequest { id: key.id.clone() }, &admin_key))
+        .await?
+        .into_inner();
+    assert!(revoked.api_key.unwrap().revoked_at.is_some());
+    let status = echo_client.unary_echo(with_key(echo(), &created.secret)).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+    assert!(api_keys.list_api_keys(with_key(list, &admin_key)).await?.into_inner().api_keys.is_empty());
+    let list = ListApiKeysRequest { owner: String::new(), include_revoked: true };
+    assert_eq!(api_keys.list_api_keys(with_key(list, &admin_key)).await?.into_inner().api_keys.len(), 2);
+
+    let status = api_keys
+        .revoke_api_key(with_key(RevokeApiKeyRequest { id: "not-a-uuid".to_string() }, &admin_key))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    drop(api_keys);
+    drop(echo_client);
+    shutdown.trigger();
+    server.await?;
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::fmt::Debug;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use futures_util::future::BoxFuture;
+use prost::Message;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::Layer;
+use tonic::body::BoxBody;
+use tonic::codegen::{http, Body, Bytes};
+use tonic::server::NamedService;
+use tonic::transport::server::Routes;
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::{ServerStreamingEchoRequest, ServerStreamingEchoResponse, UnaryEchoRequest};
+use rust_server::metrics::{metrics, MethodLabels};
+use rust_server::server::{serve_listeners, ListenerConfig, Middleware, ShutdownTrigger};
+
+type Fields = HashMap<String, String>;
+
+/// Collects the fields of every event logged by the panic catcher.
+#[derive(Clone, Default)]
+struct PanicEvents(Arc<Mutex<Vec<Fields>>>);
+
+struct Visitor<'a>(&'a mut Fields);
+
+impl Visit for Visitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.insert(field.name().to_string(), format!("{:?}", value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+}
+
+impl<S: Subscriber> Layer<S> for PanicEvents {
+    fn on_event(&self, event: &Event<'_>, _: tracing_subscriber::layer::Context<'_, S>) {
+        if event.metadata().target() == "rust_server::server::catch_panic" {
+            let mut fields = Fields::new();
+            event.record(&mut Visitor(&mut fields));
+            self.0.lock().unwrap().push(fields);
+        }
+    }
+}
+
+/// Response stream that sends one message, then panics.
+struct PanickingBody {
+    sent: bool,
+}
+
+impl Body for PanickingBody {
+    type Data = Bytes;
+    type Error = tonic::Status;
+
+    fn poll_data(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        if self.sent {
+            panic!("stream failed");
+        }
+        self.sent = true;
+
+        let message = ServerStreamingEchoResponse { message: "hello".to_string(), sequence: 0 }.encode_to_vec();
+        let mut frame = vec![0];
+        frame.extend_from_slice(&(message.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&message);
+        Poll::Ready(Some(Ok(Bytes::from(frame))))
+    }
+
+    fn poll_trailers(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<Option<http::HeaderMap>, Self::Error>> {
+        Poll::Ready(Ok(None))
+    }
+}
+
+/// Echo service whose unary handler panics, and whose server stream panics
+/// after its first message.
+#[derive(Clone)]
+struct PanickingEcho;
+
+impl NamedService for PanickingEcho {
+    const NAME: &'static str = "echo.v1.EchoService";
+}
+
+impl tower::Service<http::Request<tonic::transport::Body>> for PanickingEcho {
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<tonic::transport::Body>) -> Self::Future {
+        if request.uri().path() != "/echo.v1.EchoService/ServerStreamingEcho" {
+            return Box::pin(async { panic!("handler failed") });
+        }
+
+        let response = http::Response::builder()
+            .header("content-type", "application/grpc")
+            .body(BoxBody::new(PanickingBody { sent: false }))
+            .unwrap();
+        Box::pin(async { Ok(response) })
+    }
+}
+
+#[tokio::test]
+async fn turns_panics_into_internal_errors() -> Result<(), Box<dyn std::error::Error>> {
+    let events = PanicEvents::default();
+    let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(events.clone()));
+    let labels = MethodLabels { method: "/echo.v1.EchoService/UnaryEcho".to_string() };
+    let panics = metrics().panics.get_or_create(&labels).get();
+
+    let shutdown = ShutdownTrigger::new();
+    let listeners = [ListenerConfig::parse("127.0.0.1:50256")?];
+    let server = serve_listeners(Routes::new(PanickingEcho), &listeners, None, Middleware::default(), shutdown.clone())?;
+
+    let mut request = tonic::Request::new(UnaryEchoRequest { message: "hello".to_string() });
+    request.metadata_mut().insert("x-request-id", "panic-1".parse()?);
+    let mut client = EchoServiceClient::connect("http://127.0.0.1:50256").await?;
+    let status = client.unary_echo(request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Internal);
+    let error: serde_json::Value = serde_json::from_str(status.metadata().get("x-tonic-error").unwrap().to_str()?)?;
+    assert_eq!(error["type"], "InternalServerError");
+    assert_eq!(error["request_id"], "panic-1");
+    assert_eq!(metrics().panics.get_or_create(&labels).get(), panics + 1);
+
+    // The location and backtrace are fields of their own
+    let event = events.0.lock().unwrap()[0].clone();
+    assert_eq!(event["error"], "/echo.v1.EchoService/UnaryEcho panicked: handler failed");
+    assert!(event["location"].starts_with("tests/rpcs/catch_panic.rs:"), "{:?}", event);
+    assert!(!event["backtrace"].is_empty());
+
+    // The connection survives the panic
+    let status = client.unary_echo(UnaryEchoRequest { message: "hello".to_string() }).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Internal);
+
+    // Panics while streaming end the stream with an INTERNAL status
+    let labels = MethodLabels { method: "/echo.v1.EchoService/ServerStreamingEcho".to_string() };
+    let panics = metrics().panics.get_or_create(&labels).get();
+    let request = ServerStreamingEchoRequest { message: "hello".to_string(), count: 2, interval_ms: 0 };
+    let mut stream = client.server_streaming_echo(request).await?.into_inner();
+    assert_eq!(stream.message().await?.unwrap().message, "hello");
+    let status = stream.message().await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Internal);
+    assert_eq!(metrics().panics.get_or_create(&labels).get(), panics + 1);
+
+    drop(client);
+    shutdown.trigger();
+    server.await?;
+    Ok(())
+}
@@ -0,0 +1,190 @@
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::{ClientStreamingEchoRequest, UnaryEchoRequest};
+use rust_server::config::{LimitsOptions, SloOptions};
+use rust_server::server::{serve_listeners, ConcurrencyLimits, ListenerConfig, Middleware, Objectives, ShutdownTrigger};
+use crate::setup_test_context;
+
+const CLIENT_STREAMING: &str = "/echo.v1.EchoService/ClientStreamingEcho";
+
+#[test]
+fn resolves_method_limits() -> Result<(), Box<dyn std::error::Error>> {
+    let methods = [
+        "/echo.v1.EchoService/UnaryEcho".to_string(),
+        CLIENT_STREAMING.to_string(),
+        "/other.v1.OtherService/Call".to_string(),
+    ];
+    let options = LimitsOptions {
+        default_concurrency: 64,
+        methods: "/echo.v1.EchoService/*=8; /echo.v1.EchoService/UnaryEcho=2".to_string(),
+        ..Default::default()
+    };
+
+    let limits = ConcurrencyLimits::new(&options, &Objectives::default(), &methods)?;
+    assert_eq!(limits.limit("/echo.v1.EchoService/UnaryEcho"), Some(2));
+    assert_eq!(limits.limit(CLIENT_STREAMING), Some(8));
+    assert_eq!(limits.limit("/other.v1.OtherService/Call"), Some(64));
+    assert_eq!(limits.limit("/unknown.v1.Service/Call"), None);
+
+    assert!(ConcurrencyLimits::parse_rules("/echo.v1.EchoService/UnaryEcho=0").is_err());
+    assert!(ConcurrencyLimits::parse_rules("echo.v1.EchoService/UnaryEcho=1").is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn sheds_requests_over_the_limit() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("sheds_requests_over_the_limit", 50225).await;
+    let options = LimitsOptions {
+        methods: format!("{}=1", CLIENT_STREAMING),
+        adaptive: true,
+        target_latency_ms: 1,
+        min_concurrency: 1,
+        ..Default::default()
+    };
+    let limits = ConcurrencyLimits::new(&options, &Objectives::default(), &[CLIENT_STREAMING.to_string(), "/echo.v1.EchoService/UnaryEcho".to_string()])?;
+    let middleware = Middleware { limits: limits.clone(), ..Default::default() };
+
+    let shutdown = ShutdownTrigger::new();
+    let server = serve_listeners(ctx.routes(), &[ListenerConfig::parse("127.0.0.1:50226")?], None, middleware, shutdown.clone())?;
+    let mut client = EchoServiceClient::connect("http://127.0.0.1:50226").await?;
+
+    // The response only starts once the stream ends, holding the only slot
+    let (sender, receiver) = mpsc::channel(1);
+    sender.send(ClientStreamingEchoRequest { message: "slow".to_string() }).await?;
+    let mut slow_client = client.clone();
+    let slow = tokio::spawn(async move { slow_client.client_streaming_echo(ReceiverStream::new(receiver)).await });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let request = tokio_stream::iter([ClientStreamingEchoRequest { message: "fast".to_string() }]);
+    let status = client.client_streaming_echo(request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+    assert_eq!(status.metadata().get("grpc-retry-pushback-ms").unwrap(), "100");
+
+    // Other methods aren't limited
+    let response = client.unary_echo(UnaryEchoRequest { message: "hello".to_string() }).await?;
+    assert_eq!(response.into_inner().message, "hello");
+
+    drop(sender);
+    assert_eq!(slow.await??.into_inner().messages, vec!["slow".to_string()]);
+
+    let request = tokio_stream::iter([ClientStreamingEchoRequest { message: "again".to_string() }]);
+    assert!(client.client_streaming_echo(request).await.is_ok());
+    assert_eq!(limits.limit(CLIENT_STREAMING), Some(1));
+
+    drop(client);
+    shutdown.trigger();
+    server.await?;
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn adapts_limit_to_latency() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("adapts_limit_to_latency", 50227).await;
+    let options = LimitsOptions {
+        methods: format!("{}=10", CLIENT_STREAMING),
+        adaptive: true,
+        target_latency_ms: 50,
+        min_concurrency: 2,
+        ..Default::default()
+    };
+    let limits = ConcurrencyLimits::new(&options, &Objectives::default(), &[CLIENT_STREAMING.to_string()])?;
+    let middleware = Middleware { limits: limits.clone(), ..Default::default() };
+
+    let shutdown = ShutdownTrigger::new();
+    let server = serve_listeners(ctx.routes(), &[ListenerConfig::parse("127.0.0.1:50228")?], None, middleware, shutdown.clone())?;
+    let mut client = EchoServiceClient::connect("http://127.0.0.1:50228").await?;
+
+    let slow_request = |client: &mut EchoServiceClient<_>| {
+        let mut client = client.clone();
+        async move {
+            let (sender, receiver) = mpsc::channel(1);
+            sender.send(ClientStreamingEchoRequest { message: "slow".to_string() }).await.unwrap();
+            let call = tokio::spawn(async move { client.client_streaming_echo(ReceiverStream::new(receiver)).await });
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            drop(sender);
+            call.await.unwrap().unwrap();
+        }
+    };
+
+    slow_request(&mut client).await;
+    assert_eq!(limits.limit(CLIENT_STREAMING), Some(9));
+    for _ in 0..20 {
+        slow_request(&mut client).await;
+    }
+    assert_eq!(limits.limit(CLIENT_STREAMING), Some(2));
+
+    // Fast requests raise the limit back
+    for _ in 0..5 {
+        let request = tokio_stream::iter([ClientStreamingEchoRequest { message: "fast".to_string() }]);
+        client.client_streaming_echo(request).await?;
+    }
+    assert!(limits.limit(CLIENT_STREAMING) > Some(2));
+
+    drop(client);
+    shutdown.trigger();
+    server.await?;
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn backs_off_once_per_objective_latency() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("backs_off_once_per_objective_latency", 50269).await;
+    let methods = [CLIENT_STREAMING.to_string()];
+    let slo = SloOptions { default_objective: String::new(), methods: format!("{}=200ms@99", CLIENT_STREAMING) };
+    let objectives = Objectives::new(&slo, &methods)?;
+    // The objective's latency takes precedence over target_latency_ms
+    let options = LimitsOptions {
+        methods: format!("{}=10", CLIENT_STREAMING),
+        adaptive: true,
+        target_latency_ms: 1,
+        ..Default::default()
+    };
+    let limits = ConcurrencyLimits::new(&options, &objectives, &methods)?;
+    let middleware = Middleware { limits: limits.clone(), ..Default::default() };
+
+    let shutdown = ShutdownTrigger::new();
+    let server = serve_listeners(ctx.routes(), &[ListenerConfig::parse("127.0.0.1:50270")?], None, middleware, shutdown.clone())?;
+    let mut client = EchoServiceClient::connect("http://127.0.0.1:50270").await?;
+
+    // Slow requests completing together back off once
+    let mut senders = Vec::new();
+    let mut calls = Vec::new();
+    for _ in 0..5 {
+        let (sender, receiver) = mpsc::channel(1);
+        sender.send(ClientStreamingEchoRequest { message: "slow".to_string() }).await?;
+        let mut client = client.clone();
+        calls.push(tokio::spawn(async move { client.client_streaming_echo(ReceiverStream::new(receiver)).await }));
+        senders.push(sender);
+    }
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    drop(senders);
+    for call in calls {
+        call.await??;
+    }
+    assert_eq!(limits.limit(CLIENT_STREAMING), Some(9));
+
+    // Within the objective, the limit doesn't back off
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    let request = tokio_stream::iter([ClientStreamingEchoRequest { message: "fast".to_string() }]);
+    client.client_streaming_echo(request).await?;
+    assert_eq!(limits.limit(CLIENT_STREAMING), Some(9));
+
+    drop(client);
+    shutdown.trigger();
+    server.await?;
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use std::time::Duration;
+use sqlx::Row;
+use tonic::metadata::MetadataMap;
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::UnaryEchoRequest;
+use rust_server::config::DatabaseOptions;
+use rust_server::database::{self, get_connection};
+use rust_server::errors::ApiError;
+use rust_server::utils::deadline::{parse_grpc_timeout, Deadline};
+use crate::setup_test_context;
+
+#[test]
+fn parses_grpc_timeout() {
+    assert_eq!(parse_grpc_timeout("2S"), Some(Duration::from_secs(2)));
+    assert_eq!(parse_grpc_timeout("150m"), Some(Duration::from_millis(150)));
+    assert_eq!(parse_grpc_timeout("1H"), Some(Duration::from_secs(3600)));
+    assert_eq!(parse_grpc_timeout("100000000m"), None);
+    assert_eq!(parse_grpc_timeout("-1S"), None);
+    assert_eq!(parse_grpc_timeout("5s"), None);
+
+    let mut metadata = MetadataMap::new();
+    assert_eq!(Deadline::from_metadata(&metadata), Deadline::NONE);
+    metadata.insert("grpc-timeout", "1S".parse().unwrap());
+    let remaining = Deadline::from_metadata(&metadata).remaining().unwrap();
+    assert!(remaining < Duration::from_secs(1) && remaining > Duration::from_millis(900));
+}
+
+#[tokio::test]
+async fn deadline_bounds_database_and_redis_calls() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("deadline_bounds_database_and_redis_calls", 50224).await;
+    let options = DatabaseOptions {
+        url: format!("{}/{}", ctx.db_url, ctx.db_name),
+        max_connections: 1,
+        ..Default::default()
+    };
+    let pool = database::connect(&options).await?;
+
+    // The statement is cancelled once the deadline passes
+    let mut conn = get_connection(&pool, Deadline::after(Duration::from_millis(100))).await?;
+    let error = ApiError::from(sqlx::query("SELECT pg_sleep(5)").execute(&mut *conn).await.unwrap_err());
+    assert!(matches!(error, ApiError::DeadlineExceeded(_)), "{:?}", error);
+
+    // The only connection is taken, waiting on the pool times out
+    let error = get_connection(&pool, Deadline::after(Duration::from_millis(100))).await.unwrap_err();
+    assert!(matches!(error, ApiError::DeadlineExceeded(_)), "{:?}", error);
+    drop(conn);
+
+    // The timeout doesn't outlive the request that set it
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let mut conn = get_connection(&pool, Deadline::NONE).await?;
+    let row = sqlx::query("SHOW statement_timeout").fetch_one(&mut *conn).await?;
+    assert_eq!(row.get::<String, _>(0), "0");
+    drop(conn);
+    pool.close().await;
+
+    let error = ctx.state.cache.ping(Duration::ZERO).await.unwrap_err();
+    assert!(matches!(error, ApiError::DeadlineExceeded(_)), "{:?}", error);
+    ctx.state.cache.ping(Duration::from_secs(1)).await?;
+
+    // Too short for the server to do anything
+    let mut client = EchoServiceClient::connect(ctx.url.clone()).await?;
+    let mut request = tonic::Request::new(UnaryEchoRequest { message: "hello".to_string() });
+    request.set_timeout(Duration::from_millis(5));
+    let status = client.unary_echo(request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::DeadlineExceeded);
+
+    let mut request = tonic::Request::new(UnaryEchoRequest { message: "hello".to_string() });
+    request.set_timeout(Duration::from_secs(5));
+    client.unary_echo(request).await?;
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use prometheus_client::encoding::text::encode;
+use prometheus_client::registry::{Registry, Unit};
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::UnaryEchoRequest;
+use rust_server::metrics::{metrics, CacheLabels};
+use crate::setup_test_context;
+
+fn cache_lookups(result: &'static str) -> u64 {
+    metrics().cache_lookups.get_or_create(&CacheLabels { method: "unary_echo".to_string(), result }).get()
+}
+
+/// Renders the histograms and gauges, which can't be read directly.
+fn exported() -> String {
+    let mut registry = Registry::default();
+    registry.register_with_unit("redis_command_duration", "", Unit::Seconds, metrics().redis_command_duration.clone());
+    registry.register_with_unit("db_pool_acquire_duration", "", Unit::Seconds, metrics().db_pool_acquire_duration.clone());
+    registry.register("db_pool_connections", "", metrics().db_pool_connections.clone());
+    let mut out = String::new();
+    encode(&mut out, &registry).unwrap();
+    out
+}
+
+#[tokio::test]
+async fn records_dependency_metrics() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("records_dependency_metrics", 50259).await;
+    let mut client = EchoServiceClient::connect(ctx.url.clone()).await?;
+    let (misses, hits) = (cache_lookups("miss"), cache_lookups("hit"));
+
+    let request = UnaryEchoRequest { message: uuid::Uuid::new_v4().to_string() };
+    client.unary_echo(request.clone()).await?;
+    client.unary_echo(request).await?;
+    assert_eq!(cache_lookups("miss"), misses + 1);
+    assert_eq!(cache_lookups("hit"), hits + 1);
+
+    let exported = exported();
+    assert!(exported.contains("redis_command_duration_seconds_count{command=\"GET\",result=\"success\"}"), "{}", exported);
+    assert!(exported.contains("redis_command_duration_seconds_count{command=\"SETEX\",result=\"success\"}"), "{}", exported);
+    assert!(!exported.contains("db_pool_acquire_duration_seconds_count 0\n"), "{}", exported);
+    assert!(exported.contains("db_pool_connections "), "{}", exported);
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use std::net::SocketAddr;
+use hyper::{Body, Client, Method, Request};
+use prost::Message;
+use tokio::task::JoinHandle;
+use tonic_web::GrpcWebClientLayer;
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::UnaryEchoRequest;
+use rust_server::server::{serve_listeners, GrpcWebConfig, ListenerConfig, Middleware, ShutdownTrigger};
+use crate::{setup_test_context, TestContext};
+
+/// Serves the routes the way production does with gRPC-Web enabled.
+fn serve_grpc_web(ctx: &TestContext, port: u16) -> Result<(SocketAddr, ShutdownTrigger, JoinHandle<()>), Box<dyn std::error::Error>> {
+    let config = GrpcWebConfig {
+        allowed_origins: Some(vec!["http://localhost:8080".to_string()]),
+        allowed_headers: vec![],
+        exposed_headers: vec![],
+    };
+    let addr: SocketAddr = format!("127.0.0.1:{}", port).parse()?;
+    let middleware = Middleware { grpc_web: Some(config), ..Default::default() };
+    let shutdown = ShutdownTrigger::new();
+    let server = serve_listeners(ctx.routes(), &[ListenerConfig::parse(&addr.to_string())?], None, middleware, shutdown.clone())?;
+
+    Ok((addr, shutdown, server))
+}
+
+#[tokio::test]
+async fn grpc_web_echo() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("grpc_web_echo", 50203).await;
+    let (addr, web_shutdown, web_jh) = serve_grpc_web(&ctx, 50204)?;
+
+    let client = tower::ServiceBuilder::new()
+        .layer(GrpcWebClientLayer::new())
+        .service(Client::builder().build_http());
+    let mut client = EchoServiceClient::with_origin(client, format!("http://{}", addr).parse()?);
+
+    let response = client.unary_echo(UnaryEchoRequest {
+        message: "hello".to_string(),
+    }).await?;
+    assert_eq!(response.into_inner().message, "hello");
+
+    web_shutdown.trigger();
+    web_jh.await?;
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn grpc_web_exposes_error_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("grpc_web_exposes_error_metadata", 50205).await;
+    let (addr, web_shutdown, web_jh) = serve_grpc_web(&ctx, 50206)?;
+
+    let message = UnaryEchoRequest { message: "".to_string() }.encode_to_vec();
+    let mut frame = vec![0u8];
+    frame.extend_from_slice(&(message.len() as u32).to_be_bytes());
+    frame.extend_from_slice(&message);
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("http://{}/echo.v1.EchoService/UnaryEcho", addr))
+        .header("content-type", "application/grpc-web+proto")
+        .header("origin", "http://localhost:8080")
+        .body(Body::from(frame))?;
+    let response = Client::new().request(request).await?;
+    let headers = response.headers();
+
+    assert_eq!(headers["access-control-allow-origin"], "http://localhost:8080");
+    let exposed = headers["access-control-expose-headers"].to_str()?;
+    for header in ["x-tonic-error", "x-cache", "cache-control"] {
+        assert!(exposed.contains(header), "{} is not exposed", header);
+    }
+    assert!(headers.contains_key("x-tonic-error"));
+
+    web_shutdown.trigger();
+    web_jh.await?;
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
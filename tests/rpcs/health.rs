@@ -0,0 +1,94 @@
+use rust_server::cli::{healthcheck, HealthcheckArgs, HealthcheckTarget};
+use std::time::Duration;
+use tonic::transport::Channel;
+use tonic_health::pb::health_check_response::ServingStatus;
+use tonic_health::pb::health_client::HealthClient;
+use tonic_health::pb::HealthCheckRequest;
+use crate::setup_test_context;
+
+/// Waits for the health watcher to report `status` for `service`, instead of
+/// racing the health checker.
+async fn wait_for_status(client: &mut HealthClient<Channel>, service: &str, status: ServingStatus) -> Result<(), Box<dyn std::error::Error>> {
+    let mut updates = client.watch(HealthCheckRequest { service: service.to_string() }).await?.into_inner();
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while let Some(update) = updates.message().await? {
+            if update.status() == status {
+                return Ok(());
+            }
+        }
+        Err(format!("Health watch of '{}' ended before reporting {:?}", service, status).into())
+    })
+    .await?
+}
+
+#[tokio::test]
+async fn health_serving() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("health_serving", 50201).await;
+    let channel = Channel::from_shared(ctx.url.clone())?.connect().await?;
+    let mut client = HealthClient::new(channel);
+
+    for service in ["", "echo.v1.EchoService"] {
+        wait_for_status(&mut client, service, ServingStatus::Serving).await?;
+        let response = client.check(HealthCheckRequest {
+            service: service.to_string(),
+        }).await?;
+        assert_eq!(response.into_inner().status(), ServingStatus::Serving);
+    }
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn health_not_serving_on_shutdown() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("health_not_serving_on_shutdown", 50202).await;
+    let channel = Channel::from_shared(ctx.url.clone())?.connect().await?;
+    let mut client = HealthClient::new(channel);
+
+    ctx.shutdown.trigger();
+    wait_for_status(&mut client, "echo.v1.EchoService", ServingStatus::NotServing).await?;
+
+    let response = client.check(HealthCheckRequest {
+        service: "echo.v1.EchoService".to_string(),
+    }).await?;
+    assert_eq!(response.into_inner().status(), ServingStatus::NotServing);
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn healthcheck_command() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("healthcheck_command", 50218).await;
+    let target = HealthcheckTarget::parse("127.0.0.1:50218");
+    let args = HealthcheckArgs {
+        addr: None,
+        service: "echo.v1.EchoService".to_string(),
+        timeout: 1,
+        ca_cert: None,
+        domain: None,
+        admin: false,
+    };
+
+    let mut client = HealthClient::new(Channel::from_shared(ctx.url.clone())?.connect().await?);
+
+    wait_for_status(&mut client, &args.service, ServingStatus::Serving).await?;
+    assert_eq!(healthcheck(&target, &args).await?, ServingStatus::Serving);
+
+    ctx.shutdown.trigger();
+    wait_for_status(&mut client, &args.service, ServingStatus::NotServing).await?;
+    assert_eq!(healthcheck(&target, &args).await?, ServingStatus::NotServing);
+
+    drop(client);
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+
+    // Nothing listens anymore
+    assert!(healthcheck(&target, &args).await.is_err());
+    Ok(())
+}
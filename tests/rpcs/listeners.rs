@@ -0,0 +1,61 @@
+use std::os::unix::fs::PermissionsExt;
+use tokio::net::UnixStream;
+use tonic::transport::{Endpoint, Uri};
+use tower::service_fn;
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::UnaryEchoRequest;
+use rust_server::server::{serve_listeners, ListenerConfig, Middleware, ShutdownTrigger};
+use crate::setup_test_context;
+
+#[tokio::test]
+async fn serves_tcp_and_unix_listeners() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("serves_tcp_and_unix_listeners", 50211).await;
+    let socket_path = std::env::temp_dir().join("rust_server_listeners_test.sock");
+    let listeners = [
+        ListenerConfig::parse("127.0.0.1:50212")?,
+        ListenerConfig::parse("tcp://[::1]:50212")?,
+        ListenerConfig::parse(&format!("unix://{}?mode=600", socket_path.display()))?,
+    ];
+
+    let shutdown = ShutdownTrigger::new();
+    let routes = ctx.routes();
+    let server = serve_listeners(routes, &listeners, None, Middleware::default(), shutdown.clone())?;
+
+    let mode = std::fs::metadata(&socket_path)?.permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+    // The socket is bound in a private directory, gone once it is in place
+    let staging = format!(".{}.", std::process::id());
+    for entry in std::fs::read_dir(std::env::temp_dir())? {
+        assert!(!entry?.file_name().to_string_lossy().starts_with(&staging));
+    }
+
+    let request = || UnaryEchoRequest { message: "hello".to_string() };
+    for url in ["http://127.0.0.1:50212", "http://[::1]:50212"] {
+        let mut client = EchoServiceClient::connect(url).await?;
+        assert_eq!(client.unary_echo(request()).await?.into_inner().message, "hello");
+    }
+
+    let path = socket_path.clone();
+    let channel = Endpoint::try_from("http://[::]:50051")?
+        .connect_with_connector(service_fn(move |_: Uri| UnixStream::connect(path.clone())))
+        .await?;
+    let mut client = EchoServiceClient::new(channel);
+    assert_eq!(client.unary_echo(request()).await?.into_inner().message, "hello");
+
+    drop(client);
+    shutdown.trigger();
+    server.await?;
+    assert!(!socket_path.exists());
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
+
+#[test]
+fn rejects_invalid_listeners() {
+    for spec in ["localhost", "tcp://0.0.0.0:50051?mode=600", "unix://", "unix:///tmp/x.sock?mode=999", "0.0.0.0:50051?tls=maybe"] {
+        assert!(ListenerConfig::parse(spec).is_err(), "{} should be rejected", spec);
+    }
+}
@@ -1 +1,23 @@
-pub mod integration;
\ No newline at end of file
+pub mod integration;
+pub mod health;
+pub mod grpc_web;
+pub mod rest;
+pub mod listeners;
+pub mod tls;
+pub mod mtls;
+pub mod registry;
+pub mod streaming;
+pub mod deadline;
+pub mod concurrency;
+pub mod rate_limit;
+pub mod auth;
+pub mod api_keys;
+pub mod policy;
+pub mod request_id;
+pub mod spans;
+pub mod access_log;
+pub mod catch_panic;
+pub mod admin;
+pub mod dependency_metrics;
+pub mod slo;
+pub mod shutdown;
@@ -0,0 +1,98 @@
+use rcgen::{BasicConstraints, Certificate, CertificateParams, DnType, IsCa};
+use tonic::transport::{Channel, ClientTlsConfig, Identity};
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::UnaryEchoRequest;
+use rust_server::server::{configure_tls, serve_listeners, ClientAllowlist, ClientIdentity, ListenerConfig, Middleware, ShutdownTrigger, TlsSettings, TlsSource};
+use crate::setup_test_context;
+
+fn certificate(common_name: &str, san: &str, is_ca: bool) -> Certificate {
+    let mut params = CertificateParams::new(vec![san.to_string()]);
+    params.distinguished_name.push(DnType::CommonName, common_name);
+    if is_ca {
+        params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+    }
+    Certificate::from_params(params).unwrap()
+}
+
+fn tls_settings(cert: String, key: String, ca_cert: Option<String>) -> TlsSettings {
+    TlsSettings {
+        source: TlsSource::Pem { cert, key, ca_cert },
+        reload_interval: std::time::Duration::from_secs(30),
+        http1: false,
+    }
+}
+
+async fn connect(ca: &Certificate, client: &Certificate) -> Result<EchoServiceClient<Channel>, Box<dyn std::error::Error>> {
+    let tls = ClientTlsConfig::new()
+        .ca_certificate(tonic::transport::Certificate::from_pem(ca.serialize_pem()?))
+        .identity(Identity::from_pem(client.serialize_pem_with_signer(ca)?, client.serialize_private_key_pem()))
+        .domain_name("localhost");
+    let channel = Channel::from_static("https://127.0.0.1:50216").tls_config(tls)?.connect().await?;
+    Ok(EchoServiceClient::new(channel))
+}
+
+#[tokio::test]
+async fn mtls_client_allowlist() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("mtls_client_allowlist", 50215).await;
+    let ca = certificate("Test CA", "ca.example.com", true);
+    let server_cert = certificate("server", "localhost", false);
+    let client_a = certificate("client-a", "client-a.example.com", false);
+    let client_b = certificate("client-b", "client-b.example.com", false);
+
+    let settings = tls_settings(
+        server_cert.serialize_pem_with_signer(&ca)?,
+        server_cert.serialize_private_key_pem(),
+        Some(ca.serialize_pem()?),
+    );
+    let tls = configure_tls(Some(settings), true)?;
+    let allowlist = ClientAllowlist::parse("/echo.v1.EchoService/UnaryEcho=client-a.example.com")?;
+
+    let shutdown = ShutdownTrigger::new();
+    let routes = ctx.routes();
+    let listeners = [
+        ListenerConfig::parse("127.0.0.1:50216")?,
+        ListenerConfig::parse("127.0.0.1:50217?tls=false")?,
+    ];
+    let server = serve_listeners(routes, &listeners, tls, Middleware { allowlist, ..Default::default() }, shutdown.clone())?;
+
+    let request = || UnaryEchoRequest { message: "hello".to_string() };
+    connect(&ca, &client_a).await?.unary_echo(request()).await?;
+
+    let status = connect(&ca, &client_b).await?.unary_echo(request()).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+    let mut plaintext = EchoServiceClient::connect("http://127.0.0.1:50217").await?;
+    let status = plaintext.unary_echo(request()).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    assert!(status.metadata().contains_key("x-tonic-error"));
+
+    drop(plaintext);
+    shutdown.trigger();
+    server.await?;
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
+
+#[test]
+fn client_identity_from_certificate() {
+    let ca = certificate("Test CA", "ca.example.com", true);
+    let client = certificate("client-a", "client-a.example.com", false);
+    let identity = ClientIdentity::from_der(&client.serialize_der_with_signer(&ca).unwrap()).unwrap();
+
+    assert_eq!(identity.subject, "CN=client-a");
+    assert_eq!(identity.common_name.as_deref(), Some("client-a"));
+    assert_eq!(identity.sans, vec!["client-a.example.com".to_string()]);
+}
+
+#[test]
+fn tls_required_fails_closed() {
+    let invalid = || Some(tls_settings("not a certificate".to_string(), "not a key".to_string(), None));
+
+    assert!(configure_tls(None, true).is_err());
+    assert!(configure_tls(invalid(), true).is_err());
+    assert!(configure_tls(None, false).unwrap().is_none());
+    assert!(configure_tls(invalid(), false).unwrap().is_none());
+}
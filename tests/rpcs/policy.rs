@@ -0,0 +1,159 @@
+use serde_json::json;
+use tonic::transport::Channel;
+use tonic_health::pb::health_client::HealthClient;
+use tonic_health::pb::HealthCheckRequest;
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::{ServerStreamingEchoRequest, UnaryEchoRequest};
+use rust_server::config::AuthOptions;
+use rust_server::database::{get_connection, insert_api_key};
+use rust_server::errors::ApiError;
+use rust_server::server::{serve_listeners, ApiKeyAuthenticator, Claims, ClientIdentity, DefaultAction, JwtAuthenticator, ListenerConfig, Middleware, Policy, PolicyRule, ShutdownTrigger};
+use rust_server::utils::deadline::Deadline;
+use crate::setup_test_context;
+
+const POLICY: &str = r#"
+default = "deny"
+
+[[rules]]
+method = "/grpc.health.v1.Health/*"
+public = true
+
+[[rules]]
+method = "/echo.v1.EchoService/*"
+roles = ["admin"]
+
+[[rules]]
+method = "/echo.v1.EchoService/UnaryEcho"
+scopes = ["echo:read"]
+"#;
+
+fn policy_file(name: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(name);
+    std::fs::write(&path, POLICY).unwrap();
+    path
+}
+
+fn claims(value: serde_json::Value) -> Claims {
+    Claims(value.as_object().unwrap().clone())
+}
+
+fn with_key<T>(message: T, key: &str) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    request.metadata_mut().insert("x-api-key", key.parse().unwrap());
+    request
+}
+
+fn echo() -> UnaryEchoRequest {
+    UnaryEchoRequest { message: "hello".to_string() }
+}
+
+#[test]
+fn evaluates_policy_rules() {
+    let policy = Policy::load(&policy_file("rust_server_policy_rules.toml")).unwrap();
+    let reader = claims(json!({ "sub": "reader", "scope": "echo:read echo:write" }));
+    let admin = claims(json!({ "sub": "admin", "roles": ["admin"] }));
+
+    assert!(policy.check("/grpc.health.v1.Health/Check", None, None).is_ok());
+    assert!(policy.check("/echo.v1.EchoService/UnaryEcho", Some(&reader), None).is_ok());
+    assert!(matches!(policy.check("/echo.v1.EchoService/UnaryEcho", Some(&admin), None), Err(ApiError::PermissionDenied(_))));
+    assert!(matches!(policy.check("/echo.v1.EchoService/UnaryEcho", None, None), Err(ApiError::Unauthenticated(_))));
+
+    // The service wildcard applies to methods without their own rule
+    assert!(policy.check("/echo.v1.EchoService/ServerStreamingEcho", Some(&admin), None).is_ok());
+    assert!(matches!(policy.check("/echo.v1.EchoService/ServerStreamingEcho", Some(&reader), None), Err(ApiError::PermissionDenied(_))));
+    assert!(matches!(policy.check("/apikey.v1.ApiKeyService/ListApiKeys", Some(&admin), None), Err(ApiError::PermissionDenied(_))));
+
+    assert!(Policy::default().check("/apikey.v1.ApiKeyService/ListApiKeys", None, None).is_ok());
+
+    let path = std::env::temp_dir().join("rust_server_policy_invalid.yaml");
+    std::fs::write(&path, "rules:\n  - method: /echo.v1.EchoService/*\n    groups: [admin]\n").unwrap();
+    assert!(Policy::load(&path).is_err());
+    std::fs::write(&path, "rules:\n  - method: echo.v1.EchoService/UnaryEcho\n").unwrap();
+    assert!(Policy::load(&path).is_err());
+}
+
+fn rule(method: &str) -> PolicyRule {
+    PolicyRule { method: method.to_string(), public: false, roles: Vec::new(), scopes: Vec::new(), clients: Vec::new() }
+}
+
+#[test]
+fn checks_client_certificates() {
+    let billing = ClientIdentity { subject: "CN=billing".to_string(), common_name: Some("billing".to_string()), sans: Vec::new() };
+    let other = ClientIdentity { subject: "CN=other".to_string(), common_name: Some("other".to_string()), sans: Vec::new() };
+    let admin = claims(json!({ "sub": "admin", "roles": ["admin"] }));
+    let policy = Policy::new(DefaultAction::Deny, vec![
+        PolicyRule { clients: vec!["billing".to_string()], ..rule("/echo.v1.EchoService/UnaryEcho") },
+        PolicyRule { clients: vec!["billing".to_string()], roles: vec!["admin".to_string()], ..rule("/echo.v1.EchoService/*") },
+    ]).unwrap();
+
+    // Certificates named by the rule are enough, claims only when the rule lists roles or scopes
+    assert!(policy.check("/echo.v1.EchoService/UnaryEcho", None, Some(&billing)).is_ok());
+    assert!(matches!(policy.check("/echo.v1.EchoService/UnaryEcho", None, Some(&other)), Err(ApiError::PermissionDenied(_))));
+    assert!(matches!(policy.check("/echo.v1.EchoService/UnaryEcho", Some(&admin), None), Err(ApiError::PermissionDenied(_))));
+    assert!(matches!(policy.check("/echo.v1.EchoService/UnaryEcho", None, None), Err(ApiError::Unauthenticated(_))));
+    assert!(policy.check("/echo.v1.EchoService/ServerStreamingEcho", None, Some(&billing)).is_ok());
+    assert!(policy.check("/echo.v1.EchoService/ServerStreamingEcho", Some(&admin), Some(&other)).is_ok());
+
+    assert!(Policy::new(DefaultAction::Allow, vec![PolicyRule { public: true, clients: vec!["billing".to_string()], ..rule("/a.B/C") }]).is_err());
+}
+
+#[test]
+fn checks_public_methods() {
+    let policy = Policy::load(&policy_file("rust_server_policy_public.toml")).unwrap();
+    let public = |methods: &[&str]| methods.iter().map(|method| method.to_string()).collect::<Vec<_>>();
+
+    assert!(policy.check_public_methods(&public(&["/grpc.health.v1.Health/*"])).is_empty());
+    assert_eq!(policy.check_public_methods(&public(&["/grpc.health.v1.Health/Check", "/grpc.health.v1.Health/Watch"])).len(), 1);
+    let errors = policy.check_public_methods(&public(&["/grpc.health.v1.Health/*", "/echo.v1.EchoService/UnaryEcho"]));
+    assert_eq!(errors, vec!["/echo.v1.EchoService/UnaryEcho is in auth.public_methods but the policy requires credentials for /echo.v1.EchoService/UnaryEcho"]);
+    let errors = policy.check_public_methods(&public(&["/grpc.health.v1.Health/*", "/grpc.reflection.v1alpha.ServerReflection/*"]));
+    assert_eq!(errors, vec!["/grpc.reflection.v1alpha.ServerReflection/* is in auth.public_methods but the policy denies it by default"]);
+}
+
+#[tokio::test]
+async fn enforces_policy() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("enforces_policy", 50239).await;
+    let mut conn = get_connection(&ctx.state.pool, Deadline::NONE).await?;
+    let (_, reader_key) = insert_api_key(&mut conn, "reader", &["echo:read".to_string()], None).await?;
+    let (_, writer_key) = insert_api_key(&mut conn, "writer", &["echo:write".to_string()], None).await?;
+    drop(conn);
+
+    let options = AuthOptions { enabled: true, api_keys: true, ..Default::default() };
+    let policy = Policy::load(&policy_file("rust_server_policy_enforced.toml"))?;
+    let middleware = Middleware {
+        api_keys: ApiKeyAuthenticator::new(&ctx.state),
+        auth: JwtAuthenticator::new(&options).await?,
+        policy: policy.clone(),
+        ..Default::default()
+    };
+    let dry_run = Middleware { policy: policy.dry_run(true), ..middleware.clone() };
+    let shutdown = ShutdownTrigger::new();
+    let enforced = serve_listeners(ctx.routes(), &[ListenerConfig::parse("127.0.0.1:50240")?], None, middleware, shutdown.clone())?;
+    let logged = serve_listeners(ctx.routes(), &[ListenerConfig::parse("127.0.0.1:50241")?], None, dry_run, shutdown.clone())?;
+
+    let channel = Channel::from_static("http://127.0.0.1:50240").connect().await?;
+    let mut client = EchoServiceClient::new(channel.clone());
+    let request = HealthCheckRequest { service: String::new() };
+    HealthClient::new(channel).check(request).await?;
+    assert_eq!(client.unary_echo(with_key(echo(), &reader_key)).await?.into_inner().message, "hello");
+    let status = client.unary_echo(with_key(echo(), &writer_key)).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::PermissionDenied);
+    let stream = ServerStreamingEchoRequest { message: "hello".to_string(), count: 1, interval_ms: 0 };
+    let status = client.server_streaming_echo(with_key(stream, &reader_key)).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+    drop(client);
+
+    // Dry-run only logs the denial
+    let mut client = EchoServiceClient::connect("http://127.0.0.1:50241").await?;
+    assert_eq!(client.unary_echo(with_key(echo(), &writer_key)).await?.into_inner().message, "hello");
+
+    drop(client);
+    shutdown.trigger();
+    enforced.await?;
+    logged.await?;
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use std::time::Duration;
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::UnaryEchoRequest;
+use rust_server::config::{AuthOptions, RateLimitOptions};
+use rust_server::database::{connect_redis, get_connection, insert_api_key, CacheClient};
+use rust_server::server::{serve_listeners, ApiKeyAuthenticator, JwtAuthenticator, ListenerConfig, Middleware, Quota, RateLimits, ShutdownTrigger};
+use rust_server::utils::deadline::Deadline;
+use crate::setup_test_context;
+
+const UNARY: &str = "/echo.v1.EchoService/UnaryEcho";
+
+fn request(api_key: &str) -> tonic::Request<UnaryEchoRequest> {
+    let mut request = tonic::Request::new(UnaryEchoRequest { message: "hello".to_string() });
+    request.metadata_mut().insert("x-api-key", api_key.parse().unwrap());
+    request
+}
+
+#[test]
+fn parses_quotas() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(Quota::parse("100/1s")?, Quota { limit: 100, window: Duration::from_secs(1) });
+    assert_eq!(Quota::parse("600/m")?, Quota { limit: 600, window: Duration::from_secs(60) });
+    assert_eq!(Quota::parse("5/250ms")?, Quota { limit: 5, window: Duration::from_millis(250) });
+    assert!(Quota::parse("0/1s").is_err());
+    assert!(Quota::parse("10/1d").is_err());
+    assert!(Quota::parse("10").is_err());
+    assert!(Quota::parse("10/4294967296ms").is_err());
+
+    let rules = RateLimits::parse_rules("/echo.v1.EchoService/*=10/1s; /echo.v1.EchoService/UnaryEcho=1/1h")?;
+    assert_eq!(rules[UNARY], Quota { limit: 1, window: Duration::from_secs(3600) });
+    assert!(RateLimits::parse_rules("echo.v1.EchoService/UnaryEcho=1/1s").is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn rate_limits_each_client() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("rate_limits_each_client", 50229).await;
+    let options = RateLimitOptions {
+        methods: format!("{}=2/1h", UNARY),
+        ..Default::default()
+    };
+    let rate_limits = RateLimits::new(&options, &[UNARY.to_string()], ctx.state.cache.clone())?;
+    let auth = AuthOptions { enabled: true, api_keys: true, ..Default::default() };
+    let middleware = Middleware {
+        api_keys: ApiKeyAuthenticator::new(&ctx.state),
+        auth: JwtAuthenticator::new(&auth).await?,
+        rate_limits,
+        ..Default::default()
+    };
+
+    // New keys on each run, counters outlive a test run
+    let mut conn = get_connection(&ctx.state.pool, Deadline::NONE).await?;
+    let (_, first) = insert_api_key(&mut conn, "first", &[], None).await?;
+    let (_, second) = insert_api_key(&mut conn, "second", &[], None).await?;
+    drop(conn);
+
+    let shutdown = ShutdownTrigger::new();
+    let server = serve_listeners(ctx.routes(), &[ListenerConfig::parse("127.0.0.1:50230")?], None, middleware, shutdown.clone())?;
+    let mut client = EchoServiceClient::connect("http://127.0.0.1:50230").await?;
+
+    for remaining in ["1", "0"] {
+        let response = client.unary_echo(request(&first)).await?;
+        assert_eq!(response.metadata().get("x-ratelimit-limit").unwrap(), "2");
+        assert_eq!(response.metadata().get("x-ratelimit-remaining").unwrap(), remaining);
+    }
+
+    let status = client.unary_echo(request(&first)).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+    assert_eq!(status.metadata().get("x-ratelimit-remaining").unwrap(), "0");
+    let reset = status.metadata().get("x-ratelimit-reset").unwrap().to_str()?.parse::<u64>()?;
+    assert!(reset > 0 && reset <= 3600);
+    assert!(status.metadata().get("grpc-retry-pushback-ms").is_some());
+
+    // Other clients have their own quota, from the same address
+    let response = client.unary_echo(request(&second)).await?;
+    assert_eq!(response.metadata().get("x-ratelimit-remaining").unwrap(), "1");
+
+    drop(client);
+    shutdown.trigger();
+    server.await?;
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn rate_limits_fail_open() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("rate_limits_fail_open", 50231).await;
+    let unreachable = CacheClient::new(connect_redis("redis://127.0.0.1:1".to_string())?, 60);
+    let options = |fail_open| RateLimitOptions {
+        methods: format!("{}=1/1h", UNARY),
+        fail_open,
+        ..Default::default()
+    };
+
+    let shutdown = ShutdownTrigger::new();
+    let listeners = [ListenerConfig::parse("127.0.0.1:50232")?, ListenerConfig::parse("127.0.0.1:50233")?];
+    let middleware = |fail_open| -> Result<Middleware, Box<dyn std::error::Error>> {
+        let rate_limits = RateLimits::new(&options(fail_open), &[UNARY.to_string()], unreachable.clone())?;
+        Ok(Middleware { rate_limits, ..Default::default() })
+    };
+    let open = serve_listeners(ctx.routes(), &listeners[..1], None, middleware(true)?, shutdown.clone())?;
+    let closed = serve_listeners(ctx.routes(), &listeners[1..], None, middleware(false)?, shutdown.clone())?;
+
+    let mut client = EchoServiceClient::connect("http://127.0.0.1:50232").await?;
+    for _ in 0..2 {
+        let response = client.unary_echo(UnaryEchoRequest { message: "hello".to_string() }).await?;
+        assert!(response.metadata().get("x-ratelimit-limit").is_none());
+    }
+
+    let mut client = EchoServiceClient::connect("http://127.0.0.1:50233").await?;
+    let status = client.unary_echo(UnaryEchoRequest { message: "hello".to_string() }).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unavailable);
+
+    drop(client);
+    shutdown.trigger();
+    open.await?;
+    closed.await?;
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn rate_limits_each_address_before_auth() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("rate_limits_each_address_before_auth", 50267).await;
+    let options = RateLimitOptions {
+        per_ip_quota: "2/5s".to_string(),
+        ..Default::default()
+    };
+    let rate_limits = RateLimits::new(&options, &[UNARY.to_string()], ctx.state.cache.clone())?;
+    let auth = AuthOptions { enabled: true, api_keys: true, ..Default::default() };
+    let middleware = Middleware {
+        api_keys: ApiKeyAuthenticator::new(&ctx.state),
+        auth: JwtAuthenticator::new(&auth).await?,
+        rate_limits,
+        ..Default::default()
+    };
+
+    let shutdown = ShutdownTrigger::new();
+    let server = serve_listeners(ctx.routes(), &[ListenerConfig::parse("127.0.0.1:50268")?], None, middleware, shutdown.clone())?;
+    let mut client = EchoServiceClient::connect("http://127.0.0.1:50268").await?;
+
+    // Failed attempts use up the quota of the address
+    for _ in 0..2 {
+        let status = client.unary_echo(request("ak_unknown")).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+    let status = client.unary_echo(request("ak_unknown")).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+    assert_eq!(status.metadata().get("x-ratelimit-limit").unwrap(), "2");
+
+    drop(client);
+    shutdown.trigger();
+    server.await?;
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use futures_util::{FutureExt, StreamExt};
+use tonic::body::BoxBody;
+use tonic::codegen::http::{self, HeaderValue};
+use tonic::transport::{Channel, Server};
+use tonic_reflection::pb::server_reflection_client::ServerReflectionClient;
+use tonic_reflection::pb::server_reflection_request::MessageRequest;
+use tonic_reflection::pb::server_reflection_response::MessageResponse;
+use tonic_reflection::pb::ServerReflectionRequest;
+use tower::util::MapResponseLayer;
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::echo_service_server::EchoServiceServer;
+use protos::echo::v1::UnaryEchoRequest;
+use rust_server::server::{AppBuilder, AppState, ServiceModule, ServiceRegistry};
+use rust_server::server::services::v1::echo::echo_service::EchoServiceServerImpl;
+use crate::setup_test_context;
+
+/// The echo service behind a middleware of its own.
+struct LayeredEcho;
+
+impl ServiceModule for LayeredEcho {
+    fn descriptor_set(&self) -> &'static [u8] {
+        protos::echo::v1::FILE_DESCRIPTOR_SET
+    }
+
+    fn register(&self, registry: &mut ServiceRegistry, state: &AppState) {
+        let echo = EchoServiceServerImpl { pool: state.pool.clone(), cache: state.cache.clone() };
+        let layer = MapResponseLayer::new(|mut response: http::Response<BoxBody>| {
+            response.headers_mut().insert("x-layered", HeaderValue::from_static("echo"));
+            response
+        });
+        registry.add_layered_service(EchoServiceServer::new(echo), layer);
+    }
+}
+
+#[tokio::test]
+async fn registry_builds_routes_from_modules() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("registry_builds_routes_from_modules", 50219).await;
+    let app = AppBuilder::empty().module(&LayeredEcho).build(&ctx.state);
+    assert_eq!(app.health_names, vec!["echo.v1.EchoService"]);
+
+    let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+    let server = tokio::spawn(
+        Server::builder()
+            .add_routes(app.routes)
+            .serve_with_shutdown("127.0.0.1:50220".parse()?, stopped.map(|_| ())),
+    );
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    let mut client = EchoServiceClient::connect("http://127.0.0.1:50220").await?;
+    let response = client.unary_echo(UnaryEchoRequest { message: "hello".to_string() }).await?;
+    assert_eq!(response.metadata().get("x-layered").unwrap(), "echo");
+
+    let channel = Channel::from_static("http://127.0.0.1:50220").connect().await?;
+    let mut reflection = ServerReflectionClient::new(channel);
+    let request = ServerReflectionRequest {
+        host: String::new(),
+        message_request: Some(MessageRequest::ListServices(String::new())),
+    };
+    let mut responses = reflection.server_reflection_info(futures_util::stream::iter([request])).await?.into_inner();
+    let services = match responses.next().await.unwrap()?.message_response {
+        Some(MessageResponse::ListServicesResponse(list)) => list.service.into_iter().map(|s| s.name).collect::<Vec<_>>(),
+        other => panic!("Unexpected reflection response {:?}", other),
+    };
+    assert!(services.contains(&"echo.v1.EchoService".to_string()), "{:?}", services);
+    assert!(services.contains(&"grpc.health.v1.Health".to_string()), "{:?}", services);
+
+    drop(client);
+    drop(reflection);
+    stop.send(()).unwrap();
+    server.await??;
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
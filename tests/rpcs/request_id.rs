@@ -0,0 +1,78 @@
+use hyper::{Body, Client, Method, Request, StatusCode};
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::UnaryEchoRequest;
+use rust_server::server::{
+    serve_listeners, start_rest_gateway, AppBuilder, DefaultAction, ListenerConfig, Middleware, Policy, ShutdownTrigger,
+};
+use crate::setup_test_context;
+
+fn with_id(message: &str, id: &str) -> tonic::Request<UnaryEchoRequest> {
+    let mut request = tonic::Request::new(UnaryEchoRequest { message: message.to_string() });
+    request.metadata_mut().insert("x-request-id", id.parse().unwrap());
+    request
+}
+
+fn error_json(status: &tonic::Status) -> serde_json::Value {
+    serde_json::from_str(status.metadata().get("x-tonic-error").unwrap().to_str().unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn propagates_request_ids() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("propagates_request_ids", 50245).await;
+    let shutdown = ShutdownTrigger::new();
+    let denied = Middleware { policy: Policy::new(DefaultAction::Deny, Vec::new())?, ..Default::default() };
+    let server = serve_listeners(ctx.routes(), &[ListenerConfig::parse("127.0.0.1:50242")?], None, Middleware::default(), shutdown.clone())?;
+    let denying = serve_listeners(ctx.routes(), &[ListenerConfig::parse("127.0.0.1:50243")?], None, denied, shutdown.clone())?;
+    let rest = start_rest_gateway(
+        AppBuilder::new().rest_gateway()?,
+        ctx.routes(),
+        Middleware::default(),
+        "127.0.0.1:50244".parse()?,
+        shutdown.clone(),
+    )
+    .await?;
+
+    let mut client = EchoServiceClient::connect("http://127.0.0.1:50242").await?;
+    let response = client.unary_echo(with_id("hello", "req-123")).await?;
+    assert_eq!(response.metadata().get("x-request-id").unwrap(), "req-123");
+
+    // Missing or unusable IDs are replaced
+    let response = client.unary_echo(UnaryEchoRequest { message: "hello".to_string() }).await?;
+    let generated = response.metadata().get("x-request-id").unwrap().to_str()?;
+    assert!(uuid::Uuid::parse_str(generated).is_ok());
+    let response = client.unary_echo(with_id("hello", &"a".repeat(200))).await?;
+    assert_ne!(response.metadata().get("x-request-id").unwrap().to_str()?, "a".repeat(200));
+
+    let status = client.unary_echo(with_id("", "req-invalid")).await.unwrap_err();
+    assert_eq!(status.metadata().get("x-request-id").unwrap(), "req-invalid");
+    assert_eq!(error_json(&status)["request_id"], "req-invalid");
+
+    // Errors raised by the middleware carry it too
+    let mut client = EchoServiceClient::connect("http://127.0.0.1:50243").await?;
+    let status = client.unary_echo(with_id("hello", "req-denied")).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::PermissionDenied);
+    assert_eq!(error_json(&status)["request_id"], "req-denied");
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("http://127.0.0.1:50244/v1/echo")
+        .header("content-type", "application/json")
+        .header("x-request-id", "req-rest")
+        .body(Body::from(r#"{"message":""}"#))?;
+    let response = Client::new().request(request).await?;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(response.headers().get("x-request-id").unwrap(), "req-rest");
+    let body: serde_json::Value = serde_json::from_slice(&hyper::body::to_bytes(response.into_body()).await?)?;
+    assert_eq!(body["request_id"], "req-rest");
+
+    drop(client);
+    shutdown.trigger();
+    server.await?;
+    denying.await?;
+    rest.await?;
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use std::net::SocketAddr;
+use hyper::{Body, Client, Method, Request, StatusCode};
+use rust_server::server::AppBuilder;
+use crate::{setup_test_context, TestContext};
+
+async fn serve_rest(ctx: &TestContext, port: u16) -> SocketAddr {
+    let app = AppBuilder::new().rest_gateway().unwrap().into_router(ctx.routes());
+
+    let addr: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+    let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    addr
+}
+
+async fn request(method: Method, uri: String, body: &str) -> (StatusCode, serde_json::Value) {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let response = Client::new().request(request).await.unwrap();
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn rest_echo() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("rest_echo", 50207).await;
+    let addr = serve_rest(&ctx, 50208).await;
+
+    let (status, body) = request(Method::POST, format!("http://{}/v1/echo", addr), r#"{"message":"hello"}"#).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["message"], "hello");
+
+    let (status, body) = request(Method::GET, format!("http://{}/v1/echo/world", addr), "").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["message"], "world");
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn rest_echo_invalid_message() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("rest_echo_invalid_message", 50209).await;
+    let addr = serve_rest(&ctx, 50210).await;
+
+    let (status, body) = request(Method::POST, format!("http://{}/v1/echo", addr), r#"{"message":""}"#).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["type"], "ValidationError");
+    assert_eq!(body["errors"][0]["field"], "message");
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use std::time::{Duration, Instant};
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::ServerStreamingEchoRequest;
+use rust_server::server::{serve_listeners, ListenerConfig, Middleware, ShutdownTrigger};
+use crate::setup_test_context;
+
+#[tokio::test]
+async fn drains_in_flight_rpcs() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("drains_in_flight_rpcs", 50262).await;
+    let shutdown = ShutdownTrigger::new();
+    let server = serve_listeners(ctx.routes(), &[ListenerConfig::parse("127.0.0.1:50263")?], None, Middleware::default(), shutdown.clone())?;
+
+    let mut client = EchoServiceClient::connect("http://127.0.0.1:50263").await?;
+    let request = ServerStreamingEchoRequest { message: "hello".to_string(), count: 3, interval_ms: 100 };
+    let mut stream = client.server_streaming_echo(request).await?.into_inner();
+    assert!(stream.message().await?.is_some());
+
+    // The RPC is in flight when the drain starts, and still gets every message
+    let started = Instant::now();
+    shutdown.trigger();
+    let mut received = 1;
+    while stream.message().await?.is_some() {
+        received += 1;
+    }
+    assert_eq!(received, 3);
+    assert!(started.elapsed() >= Duration::from_millis(150));
+
+    // The server stops once the last RPC is done
+    drop(client);
+    tokio::time::timeout(Duration::from_secs(5), server).await??;
+    assert!(EchoServiceClient::connect("http://127.0.0.1:50263").await.is_err());
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use std::time::Duration;
+use futures_util::StreamExt;
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use protos::echo::v1::{ClientStreamingEchoRequest, UnaryEchoRequest};
+use rust_server::cli::prometheus_rules;
+use rust_server::config::{LimitsOptions, SloOptions};
+use rust_server::metrics::{metrics, MethodLabels};
+use rust_server::server::{serve_listeners, ConcurrencyLimits, ListenerConfig, Middleware, Objective, Objectives, ShutdownTrigger};
+use crate::setup_test_context;
+
+const UNARY: &str = "/echo.v1.EchoService/UnaryEcho";
+const CLIENT_STREAMING: &str = "/echo.v1.EchoService/ClientStreamingEcho";
+
+fn counts(method: &str) -> (u64, u64, u64) {
+    let labels = MethodLabels { method: method.to_string() };
+    (
+        metrics().slo_requests.get_or_create(&labels).get(),
+        metrics().slo_errors.get_or_create(&labels).get(),
+        metrics().slo_slow_requests.get_or_create(&labels).get(),
+    )
+}
+
+#[test]
+fn parses_objectives() -> Result<(), Box<dyn std::error::Error>> {
+    let objective = Objective::parse("99.9%, 250ms@99")?;
+    assert_eq!(objective.success_rate, Some(99.9));
+    assert_eq!(objective.latency, Some((Duration::from_millis(250), 99.0)));
+    assert_eq!(Objective::parse("2s@95")?.success_rate, None);
+    for invalid in ["", "99.9", "100%", "250@99", "0ms@99", "250ms@101"] {
+        assert!(Objective::parse(invalid).is_err(), "{}", invalid);
+    }
+    assert!(Objectives::parse_rules("echo.v1.EchoService/UnaryEcho=99%").is_err());
+
+    let options = SloOptions { default_objective: "99%".to_string(), methods: format!("{}=99.5%,100ms@90", UNARY) };
+    let methods = vec![UNARY.to_string(), CLIENT_STREAMING.to_string()];
+    let objectives = Objectives::new(&options, &methods)?;
+    assert_eq!(objectives.get(CLIENT_STREAMING), Some(&Objective { success_rate: Some(99.0), latency: None }));
+
+    let rules = prometheus_rules(&objectives)?;
+    // Both budgets of UnaryEcho and the error budget of ClientStreamingEcho, with 4 windows each
+    assert_eq!(rules.matches("- alert:").count(), 12);
+    assert_eq!(rules.matches("alert: SloLatencyBudgetBurn").count(), 4);
+    assert!(rules.contains("[1h])) > 14.4 * 0.1\\nand\\n"), "{}", rules);
+    assert!(rules.contains("> 6 * 0.005"), "{}", rules);
+    Ok(())
+}
+
+#[tokio::test]
+async fn records_objectives() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("records_objectives", 50260).await;
+    let methods = [UNARY.to_string(), CLIENT_STREAMING.to_string()];
+    let options = SloOptions { default_objective: String::new(), methods: format!("{}=99%;{}=99%,20ms@99", UNARY, CLIENT_STREAMING) };
+    let objectives = Objectives::new(&options, &methods)?;
+    let limits = LimitsOptions { methods: format!("{}=1", CLIENT_STREAMING), ..Default::default() };
+    let limits = ConcurrencyLimits::new(&limits, &objectives, &methods)?;
+    let middleware = Middleware { objectives, limits, ..Default::default() };
+    let shutdown = ShutdownTrigger::new();
+    let server = serve_listeners(ctx.routes(), &[ListenerConfig::parse("127.0.0.1:50261")?], None, middleware, shutdown.clone())?;
+    let mut client = EchoServiceClient::connect("http://127.0.0.1:50261").await?;
+
+    // Invalid requests are the caller's fault and don't spend the error budget
+    let before = counts(UNARY);
+    client.unary_echo(UnaryEchoRequest { message: "hello".to_string() }).await?;
+    client.unary_echo(UnaryEchoRequest { message: String::new() }).await.unwrap_err();
+    assert_eq!(counts(UNARY), (before.0 + 2, before.1, before.2));
+
+    // The response only starts once the client stream ends
+    let before = counts(CLIENT_STREAMING);
+    let requests = tokio_stream::iter(["a", "b"]).then(|message| async move {
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        ClientStreamingEchoRequest { message: message.to_string() }
+    });
+    client.client_streaming_echo(requests).await?;
+    assert_eq!(counts(CLIENT_STREAMING), (before.0 + 1, before.1, before.2 + 1));
+
+    // Shedding load spends the error budget, an open client stream holds the only slot
+    let before = counts(CLIENT_STREAMING);
+    let (sender, receiver) = mpsc::channel(1);
+    sender.send(ClientStreamingEchoRequest { message: "slow".to_string() }).await?;
+    let mut slow_client = client.clone();
+    let slow = tokio::spawn(async move { slow_client.client_streaming_echo(ReceiverStream::new(receiver)).await });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let requests = tokio_stream::iter([ClientStreamingEchoRequest { message: "fast".to_string() }]);
+    let status = client.client_streaming_echo(requests).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+    drop(sender);
+    slow.await??;
+    assert_eq!(counts(CLIENT_STREAMING), (before.0 + 2, before.1 + 1, before.2 + 1));
+
+    drop(client);
+    shutdown.trigger();
+    server.await?;
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
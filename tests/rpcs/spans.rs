@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::UnaryEchoRequest;
+use rust_server::server::{serve_listeners, ListenerConfig, Middleware, ShutdownTrigger};
+use crate::setup_test_context;
+
+type Fields = HashMap<String, String>;
+
+/// Collects the fields of every closed `rpc` span.
+#[derive(Clone, Default)]
+struct RpcSpans {
+    open: Arc<Mutex<HashMap<u64, Fields>>>,
+    closed: Arc<Mutex<Vec<Fields>>>,
+}
+
+struct Visitor<'a>(&'a mut Fields);
+
+impl Visit for Visitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.insert(field.name().to_string(), format!("{:?}", value));
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for RpcSpans {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, _: Context<'_, S>) {
+        if attrs.metadata().name() == "rpc" {
+            let mut fields = Fields::new();
+            attrs.record(&mut Visitor(&mut fields));
+            self.open.lock().unwrap().insert(id.into_u64(), fields);
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, _: Context<'_, S>) {
+        if let Some(fields) = self.open.lock().unwrap().get_mut(&id.into_u64()) {
+            values.record(&mut Visitor(fields));
+        }
+    }
+
+    fn on_close(&self, id: Id, _: Context<'_, S>) {
+        if let Some(fields) = self.open.lock().unwrap().remove(&id.into_u64()) {
+            self.closed.lock().unwrap().push(fields);
+        }
+    }
+}
+
+fn with_id(message: &str, id: &str) -> tonic::Request<UnaryEchoRequest> {
+    let mut request = tonic::Request::new(UnaryEchoRequest { message: message.to_string() });
+    request.metadata_mut().insert("x-request-id", id.parse().unwrap());
+    request
+}
+
+#[tokio::test]
+async fn records_rpc_spans() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("records_rpc_spans", 50246).await;
+    let spans = RpcSpans::default();
+    let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(spans.clone()));
+
+    let shutdown = ShutdownTrigger::new();
+    let server = serve_listeners(ctx.routes(), &[ListenerConfig::parse("127.0.0.1:50247")?], None, Middleware::default(), shutdown.clone())?;
+
+    let mut client = EchoServiceClient::connect("http://127.0.0.1:50247").await?;
+    client.unary_echo(with_id("hello", "span-ok")).await?;
+    client.unary_echo(with_id("", "span-invalid")).await.unwrap_err();
+    drop(client);
+    shutdown.trigger();
+    server.await?;
+
+    let closed = spans.closed.lock().unwrap().clone();
+    let span = |id: &str| closed.iter().find(|fields| fields["request_id"] == id).cloned().unwrap();
+
+    let ok = span("span-ok");
+    assert_eq!(ok["method"], "/echo.v1.EchoService/UnaryEcho");
+    assert_eq!(ok["status"], "Ok");
+    assert!(ok["peer"].starts_with("127.0.0.1:"));
+    // Trailers-only responses carry the status in their headers
+    assert_eq!(span("span-invalid")["status"], "InvalidArgument");
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
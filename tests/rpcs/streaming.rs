@@ -0,0 +1,102 @@
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::{BidirectionalStreamingEchoRequest, ClientStreamingEchoRequest, ServerStreamingEchoRequest};
+use rust_server::metrics::{metrics, StreamLabels};
+use crate::setup_test_context;
+
+fn sent_server_stream_messages() -> u64 {
+    metrics()
+        .streamed_messages
+        .get_or_create(&StreamLabels { method: "ServerStreamingEcho", direction: "sent" })
+        .get()
+}
+
+#[tokio::test]
+async fn server_streaming_echo() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("server_streaming_echo", 50221).await;
+    let mut client = EchoServiceClient::connect(ctx.url.clone()).await?;
+
+    let request = |count, interval_ms| ServerStreamingEchoRequest { message: "hello".to_string(), count, interval_ms };
+    let mut stream = client.server_streaming_echo(request(3, 0)).await?.into_inner();
+    for sequence in 0..3 {
+        let response = stream.message().await?.unwrap();
+        assert_eq!((response.message.as_str(), response.sequence), ("hello", sequence));
+    }
+    assert!(stream.message().await?.is_none());
+
+    let status = client.server_streaming_echo(request(0, 20_000)).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    // Dropping the stream stops the server from producing more responses
+    let sent = sent_server_stream_messages();
+    let mut stream = client.server_streaming_echo(request(100, 50)).await?.into_inner();
+    stream.message().await?.unwrap();
+    stream.message().await?.unwrap();
+    drop(stream);
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    let sent_after_cancel = sent_server_stream_messages();
+    assert!(sent_after_cancel - sent <= 3, "{} messages sent", sent_after_cancel - sent);
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(sent_server_stream_messages(), sent_after_cancel);
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_streaming_echo() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("client_streaming_echo", 50222).await;
+    let mut client = EchoServiceClient::connect(ctx.url.clone()).await?;
+
+    let requests = |messages: &[&str]| {
+        let requests = messages
+            .iter()
+            .map(|message| ClientStreamingEchoRequest { message: message.to_string() })
+            .collect::<Vec<_>>();
+        tokio_stream::iter(requests)
+    };
+
+    let response = client.client_streaming_echo(requests(&["a", "b", "c"])).await?.into_inner();
+    assert_eq!(response.messages, vec!["a", "b", "c"]);
+
+    let status = client.client_streaming_echo(requests(&["a", "", "c"])).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    let status = client.client_streaming_echo(requests(&[])).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn bidirectional_streaming_echo() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("bidirectional_streaming_echo", 50223).await;
+    let mut client = EchoServiceClient::connect(ctx.url.clone()).await?;
+
+    let (requests, outbound) = mpsc::channel(1);
+    let mut stream = client.bidirectional_streaming_echo(ReceiverStream::new(outbound)).await?.into_inner();
+
+    // Every message is answered before the next one is sent
+    for message in ["ping", "pong"] {
+        requests.send(BidirectionalStreamingEchoRequest { message: message.to_string() }).await?;
+        assert_eq!(stream.message().await?.unwrap().message, message);
+    }
+
+    // An invalid message ends the stream with its error
+    requests.send(BidirectionalStreamingEchoRequest { message: String::new() }).await?;
+    let status = stream.message().await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    drop(requests);
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
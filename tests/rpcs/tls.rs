@@ -0,0 +1,123 @@
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_rustls::rustls::pki_types::{CertificateDer, ServerName};
+use tokio_rustls::rustls::{ClientConfig, RootCertStore};
+use tokio_rustls::TlsConnector;
+use tonic::transport::{Certificate, Channel, ClientTlsConfig};
+use protos::echo::v1::echo_service_client::EchoServiceClient;
+use protos::echo::v1::UnaryEchoRequest;
+use rust_server::metrics::{metrics, ReloadLabels};
+use rust_server::server::{serve_listeners, GrpcWebConfig, ListenerConfig, Middleware, ShutdownTrigger, TlsAcceptor, TlsSettings, TlsSource};
+use crate::setup_test_context;
+
+/// Writes a new self-signed certificate for `localhost` and returns it.
+fn write_certificate(dir: &Path) -> String {
+    let cert = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+    let pem = cert.serialize_pem().unwrap();
+    std::fs::write(dir.join("tls.key"), cert.serialize_private_key_pem()).unwrap();
+    std::fs::write(dir.join("tls.crt"), &pem).unwrap();
+    pem
+}
+
+async fn connect(ca_cert: &str, url: &'static str) -> Result<EchoServiceClient<Channel>, tonic::transport::Error> {
+    let tls = ClientTlsConfig::new()
+        .ca_certificate(Certificate::from_pem(ca_cert))
+        .domain_name("localhost");
+    let channel = Channel::from_static(url).tls_config(tls)?.connect().await?;
+    Ok(EchoServiceClient::new(channel))
+}
+
+#[tokio::test]
+async fn tls_certificate_hot_reload() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("tls_certificate_hot_reload", 50213).await;
+    let dir = std::env::temp_dir().join("rust_server_tls_reload");
+    std::fs::create_dir_all(&dir)?;
+    let first_cert = write_certificate(&dir);
+
+    let shutdown = ShutdownTrigger::new();
+    let tls = TlsAcceptor::new(TlsSettings {
+        source: TlsSource::Files {
+            cert: dir.join("tls.crt"),
+            key: dir.join("tls.key"),
+            ca_cert: None,
+        },
+        reload_interval: Duration::from_millis(50),
+        http1: false,
+    }).expect("Invalid TLS material");
+    tls.spawn_reloader(shutdown.clone());
+
+    let routes = ctx.routes();
+    let listeners = [ListenerConfig::parse("127.0.0.1:50214")?];
+    let server = serve_listeners(routes, &listeners, Some(tls), Middleware::default(), shutdown.clone())?;
+
+    let url = "https://127.0.0.1:50214";
+    let request = || UnaryEchoRequest { message: "hello".to_string() };
+    let mut established = connect(&first_cert, url).await?;
+    established.unary_echo(request()).await?;
+
+    let reloads = metrics().tls_reloads.get_or_create(&ReloadLabels { result: "success" }).get();
+    let second_cert = write_certificate(&dir);
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(metrics().tls_reloads.get_or_create(&ReloadLabels { result: "success" }).get(), reloads + 1);
+
+    // New connections get the renewed certificate, existing ones stay open
+    assert!(connect(&first_cert, url).await.is_err());
+    connect(&second_cert, url).await?.unary_echo(request()).await?;
+    established.unary_echo(request()).await?;
+
+    let failures = metrics().tls_reloads.get_or_create(&ReloadLabels { result: "failure" }).get();
+    std::fs::write(dir.join("tls.crt"), "not a certificate")?;
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(metrics().tls_reloads.get_or_create(&ReloadLabels { result: "failure" }).get(), failures + 1);
+    connect(&second_cert, url).await?.unary_echo(request()).await?;
+
+    // A certificate renewed without its key is refused too
+    let failures = metrics().tls_reloads.get_or_create(&ReloadLabels { result: "failure" }).get();
+    let other = rcgen::generate_simple_self_signed(vec!["localhost".to_string()])?;
+    std::fs::write(dir.join("tls.crt"), other.serialize_pem()?)?;
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(metrics().tls_reloads.get_or_create(&ReloadLabels { result: "failure" }).get(), failures + 1);
+    connect(&second_cert, url).await?.unary_echo(request()).await?;
+
+    drop(established);
+    shutdown.trigger();
+    server.await?;
+    std::fs::remove_dir_all(&dir)?;
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn tls_offers_http1_for_grpc_web() -> Result<(), Box<dyn std::error::Error>> {
+    let (ctx, tx, jh) = setup_test_context("tls_offers_http1_for_grpc_web", 50264).await;
+    let cert = rcgen::generate_simple_self_signed(vec!["localhost".to_string()])?;
+    let tls = TlsAcceptor::new(TlsSettings {
+        source: TlsSource::Pem { cert: cert.serialize_pem()?, key: cert.serialize_private_key_pem(), ca_cert: None },
+        reload_interval: Duration::from_secs(30),
+        http1: true,
+    }).expect("Invalid TLS material");
+    let grpc_web = GrpcWebConfig { allowed_origins: None, allowed_headers: vec![], exposed_headers: vec![] };
+    let middleware = Middleware { grpc_web: Some(grpc_web), ..Default::default() };
+    let shutdown = ShutdownTrigger::new();
+    let server = serve_listeners(ctx.routes(), &[ListenerConfig::parse("127.0.0.1:50265")?], Some(tls), middleware, shutdown.clone())?;
+
+    let mut roots = RootCertStore::empty();
+    roots.add(CertificateDer::from(cert.serialize_der()?))?;
+    let mut config = ClientConfig::builder().with_root_certificates(roots).with_no_client_auth();
+    config.alpn_protocols = vec![b"http/1.1".to_vec()];
+    let stream = tokio::net::TcpStream::connect("127.0.0.1:50265").await?;
+    let stream = TlsConnector::from(Arc::new(config)).connect(ServerName::try_from("localhost")?, stream).await?;
+    assert_eq!(stream.get_ref().1.alpn_protocol(), Some(&b"http/1.1"[..]));
+
+    drop(stream);
+    shutdown.trigger();
+    server.await?;
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+    ctx.cleanup().await;
+    Ok(())
+}